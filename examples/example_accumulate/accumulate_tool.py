@@ -0,0 +1,66 @@
+#!/usr/bin/env python3
+"""Accumulate Example - Running totals CLI.
+
+`itertools.accumulate` is a scan: each output is the fold of every
+input so far. With no function it sums; a binary function such as
+`max` or `operator.mul` gives running maxima or products.
+
+Examples:
+    >>> running_sum([1, 2, 3, 4])
+    [1, 3, 6, 10]
+    >>> running_max([3, 1, 4, 1, 5])
+    [3, 3, 4, 4, 5]
+"""
+
+import argparse
+import operator
+from itertools import accumulate
+
+
+def running_sum(xs: list[int]) -> list[int]:
+    """Cumulative sums.
+
+    >>> running_sum([])
+    []
+    """
+    return list(accumulate(xs))
+
+
+def running_product(xs: list[int]) -> list[int]:
+    """Cumulative products.
+
+    >>> running_product([1, 2, 3, 4])
+    [1, 2, 6, 24]
+    """
+    return list(accumulate(xs, operator.mul))
+
+
+def running_max(xs: list[int]) -> list[int]:
+    """Running maximum.
+
+    >>> running_max([-2, -5])
+    [-2, -2]
+    """
+    return list(accumulate(xs, max))
+
+
+def main():
+    parser = argparse.ArgumentParser(description="Running totals tool")
+    subs = parser.add_subparsers(dest="cmd", required=True)
+
+    for name in ("sum", "product", "max"):
+        p = subs.add_parser(name)
+        p.add_argument("values", type=int, nargs="*")
+
+    args = parser.parse_args()
+    if args.cmd == "sum":
+        result = running_sum(args.values)
+    elif args.cmd == "product":
+        result = running_product(args.values)
+    else:
+        result = running_max(args.values)
+    print(" ".join(str(x) for x in result))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,35 @@
+"""Tests for accumulate_tool.py"""
+
+from pathlib import Path
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "accumulate_tool.py"
+
+run = GoldenCli(SCRIPT).run
+
+
+class TestSum:
+    def test_cumulative_sums(self):
+        stdout, _, code = run(["sum", "1", "2", "3", "4", "5"])
+        assert code == 0
+        assert stdout.strip() == "1 3 6 10 15"
+
+    def test_negative(self):
+        stdout, _, _ = run(["sum", "5", "-2", "-3"])
+        assert stdout.strip() == "5 3 0"
+
+    def test_empty(self):
+        stdout, _, code = run(["sum"])
+        assert code == 0
+        assert stdout == "\n"
+
+
+class TestCustomOp:
+    def test_product(self):
+        stdout, _, _ = run(["product", "2", "3", "4"])
+        assert stdout.strip() == "2 6 24"
+
+    def test_max(self):
+        stdout, _, _ = run(["max", "3", "1", "4", "1", "5", "9", "2"])
+        assert stdout.strip() == "3 3 4 4 5 9 9"
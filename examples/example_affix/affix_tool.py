@@ -0,0 +1,41 @@
+#!/usr/bin/env python3
+"""Affix Example - removeprefix/removesuffix CLI.
+
+Both methods return the string unchanged when the affix is absent.
+
+Examples:
+    >>> "test_parser.py".removeprefix("test_")
+    'parser.py'
+    >>> "parser.py".removesuffix(".rs")
+    'parser.py'
+"""
+
+import argparse
+
+
+def main():
+    parser = argparse.ArgumentParser(description="Affix removal tool")
+    subs = parser.add_subparsers(dest="cmd", required=True)
+
+    p = subs.add_parser("prefix")
+    p.add_argument("text")
+    p.add_argument("affix")
+    s = subs.add_parser("suffix")
+    s.add_argument("text")
+    s.add_argument("affix")
+    b = subs.add_parser("both")
+    b.add_argument("text")
+    b.add_argument("prefix")
+    b.add_argument("suffix")
+
+    args = parser.parse_args()
+    if args.cmd == "prefix":
+        print(args.text.removeprefix(args.affix))
+    elif args.cmd == "suffix":
+        print(args.text.removesuffix(args.affix))
+    elif args.cmd == "both":
+        print(args.text.removeprefix(args.prefix).removesuffix(args.suffix))
+
+
+if __name__ == "__main__":
+    main()
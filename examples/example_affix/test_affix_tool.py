@@ -0,0 +1,53 @@
+"""Tests for affix_tool.py"""
+
+from pathlib import Path
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "affix_tool.py"
+
+run = GoldenCli(SCRIPT).run
+
+
+class TestRemovePrefix:
+    def test_present(self):
+        stdout, _, code = run(["prefix", "unhappy", "un"])
+        assert code == 0
+        assert stdout.strip() == "happy"
+
+    def test_absent(self):
+        stdout, _, _ = run(["prefix", "happy", "un"])
+        assert stdout.strip() == "happy"
+
+    def test_removes_only_once(self):
+        stdout, _, _ = run(["prefix", "aaab", "a"])
+        assert stdout.strip() == "aab"
+
+    def test_whole_string(self):
+        stdout, _, code = run(["prefix", "abc", "abc"])
+        assert code == 0
+        assert stdout == "\n"
+
+
+class TestRemoveSuffix:
+    def test_present(self):
+        stdout, _, _ = run(["suffix", "main.py", ".py"])
+        assert stdout.strip() == "main"
+
+    def test_absent(self):
+        stdout, _, _ = run(["suffix", "main.rs", ".py"])
+        assert stdout.strip() == "main.rs"
+
+    def test_prefix_not_treated_as_suffix(self):
+        stdout, _, _ = run(["suffix", "pymain", "py"])
+        assert stdout.strip() == "pymain"
+
+
+class TestBoth:
+    def test_both_present(self):
+        stdout, _, _ = run(["both", "[tag]", "[", "]"])
+        assert stdout.strip() == "tag"
+
+    def test_multibyte(self):
+        stdout, _, _ = run(["both", "«hé»", "«", "»"])
+        assert stdout.strip() == "hé"
@@ -0,0 +1,60 @@
+#!/usr/bin/env python3
+"""Aliases Example - Subcommand aliases CLI.
+
+`add_parser("remove", aliases=["rm", "uninstall"])` registers extra names
+for the same subcommand. `args.cmd` holds whichever name was typed, so
+dispatch goes through `set_defaults(handler=...)` instead of comparing
+`args.cmd`.
+
+Examples:
+    >>> install(["ripgrep", "fd"])
+    'install: ripgrep fd'
+    >>> remove(["fd"])
+    'remove: fd'
+"""
+
+import argparse
+
+
+def install(packages: list[str]) -> str:
+    """Describe an install."""
+    return "install: " + " ".join(packages)
+
+
+def remove(packages: list[str]) -> str:
+    """Describe a removal."""
+    return "remove: " + " ".join(packages)
+
+
+def listing(packages: list[str]) -> str:
+    """Sorted package names, one per line.
+
+    >>> print(listing(["b", "a"]))
+    a
+    b
+    """
+    return "\n".join(sorted(packages))
+
+
+def main():
+    parser = argparse.ArgumentParser(description="Package alias tool")
+    subs = parser.add_subparsers(dest="cmd", required=True)
+
+    i = subs.add_parser("install", aliases=["i", "add"])
+    i.add_argument("packages", nargs="+")
+    i.set_defaults(handler=install)
+    r = subs.add_parser("remove", aliases=["rm", "uninstall"])
+    r.add_argument("packages", nargs="+")
+    r.set_defaults(handler=remove)
+    ls = subs.add_parser("list", aliases=["ls"])
+    ls.add_argument("packages", nargs="*")
+    ls.set_defaults(handler=listing)
+
+    args = parser.parse_args()
+    output = args.handler(args.packages)
+    if output:
+        print(output)
+
+
+if __name__ == "__main__":
+    main()
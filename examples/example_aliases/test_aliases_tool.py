@@ -0,0 +1,36 @@
+"""Tests for aliases_tool.py"""
+
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "aliases_tool.py"
+
+run = GoldenCli(SCRIPT).run
+
+
+class TestAliases:
+    @pytest.mark.parametrize("name", ["remove", "rm", "uninstall"])
+    def test_remove_aliases(self, name):
+        stdout, _, code = run([name, "fd"])
+        assert code == 0
+        assert stdout.strip() == "remove: fd"
+
+    @pytest.mark.parametrize("name", ["install", "i", "add"])
+    def test_install_aliases(self, name):
+        stdout, _, _ = run([name, "ripgrep", "fd"])
+        assert stdout.strip() == "install: ripgrep fd"
+
+    def test_list_alias(self):
+        stdout, _, _ = run(["ls", "b", "a"])
+        assert stdout == "a\nb\n"
+
+    def test_unknown_alias(self):
+        _, _, code = run(["del", "fd"])
+        assert code == 2
+
+    def test_help_lists_aliases(self):
+        stdout, _, _ = run(["--help"])
+        assert "remove,rm,uninstall" in stdout
@@ -0,0 +1,65 @@
+#!/usr/bin/env python3
+"""Assert Optimize Example - Assertion checks stripped under -O CLI.
+
+Running with `python3 -O` removes every `assert` statement, so the
+transpiled release build must omit the same checks.
+
+Examples:
+    >>> isqrt_floor(17)
+    4
+    >>> percent(1, 4)
+    25
+"""
+
+import argparse
+
+
+def isqrt_floor(n: int) -> int:
+    """Integer square root, asserting a non-negative input.
+
+    >>> isqrt_floor(0)
+    0
+    >>> isqrt_floor(16)
+    4
+    >>> isqrt_floor(24)
+    4
+    """
+    assert n >= 0, "n must be non-negative"
+    r = 0
+    while (r + 1) * (r + 1) <= n:
+        r = r + 1
+    return r
+
+
+def percent(part: int, total: int) -> int:
+    """Whole-number percentage, asserting the part fits in the total.
+
+    >>> percent(0, 10)
+    0
+    >>> percent(10, 10)
+    100
+    """
+    assert total > 0
+    assert part <= total, "part exceeds total"
+    return part * 100 // total
+
+
+def main():
+    parser = argparse.ArgumentParser(description="Assertion tool")
+    subs = parser.add_subparsers(dest="cmd", required=True)
+
+    s = subs.add_parser("isqrt")
+    s.add_argument("n", type=int)
+    p = subs.add_parser("percent")
+    p.add_argument("part", type=int)
+    p.add_argument("total", type=int)
+
+    args = parser.parse_args()
+    if args.cmd == "isqrt":
+        print(isqrt_floor(args.n))
+    elif args.cmd == "percent":
+        print(percent(args.part, args.total))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,93 @@
+"""Tests for assert_tool.py
+
+Asserts must fire in a normal run and vanish under `python3 -O`, which is
+the behavior depyler's `--strip-asserts` flag mirrors. The TestTranspiled
+cases check the generated Rust and are skipped without depyler.
+"""
+
+import re
+import subprocess
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "assert_tool.py"
+
+GOLDEN = GoldenCli(SCRIPT)
+
+# Matches assert!, assert_eq!, debug_assert! and friends
+ASSERT_MACRO = re.compile(r"\b(debug_)?assert(_eq|_ne)?!")
+
+
+@pytest.fixture(scope="module")
+def checked():
+    """Rust source in the default mode, transpiled once per module."""
+    return GOLDEN.rust_source_or_skip()
+
+
+@pytest.fixture(scope="module")
+def stripped():
+    """Rust source with --strip-asserts, transpiled once per module."""
+    return GOLDEN.rust_source_or_skip("--strip-asserts")
+
+
+def run(args, optimize=False):
+    """Run the CLI and return (stdout, stderr, returncode)."""
+    flags = ["-O"] if optimize else []
+    result = subprocess.run(
+        ["python3", *flags, str(SCRIPT)] + args,
+        capture_output=True,
+        text=True,
+    )
+    return result.stdout, result.stderr, result.returncode
+
+
+class TestAssertsEnabled:
+    def test_valid_input(self):
+        stdout, _, code = run(["isqrt", "17"])
+        assert code == 0
+        assert stdout.strip() == "4"
+
+    def test_negative_input_fails(self):
+        _, stderr, code = run(["isqrt", "-4"])
+        assert code != 0
+        assert "AssertionError" in stderr
+        assert "n must be non-negative" in stderr
+
+    def test_part_exceeds_total_fails(self):
+        _, stderr, code = run(["percent", "5", "4"])
+        assert code != 0
+        assert "part exceeds total" in stderr
+
+
+class TestAssertsStripped:
+    def test_valid_input_unchanged(self):
+        stdout, _, code = run(["isqrt", "17"], optimize=True)
+        assert code == 0
+        assert stdout.strip() == "4"
+
+    def test_negative_input_skips_check(self):
+        stdout, stderr, code = run(["isqrt", "-4"], optimize=True)
+        assert code == 0
+        assert "AssertionError" not in stderr
+        assert stdout.strip() == "0"
+
+    def test_part_exceeds_total_skips_check(self):
+        stdout, stderr, code = run(["percent", "5", "4"], optimize=True)
+        assert code == 0
+        assert "AssertionError" not in stderr
+        assert stdout.strip() == "125"
+
+
+class TestTranspiled:
+    def test_asserts_kept_by_default(self, checked):
+        assert ASSERT_MACRO.search(checked)
+        assert "n must be non-negative" in checked
+        assert "part exceeds total" in checked
+
+    def test_asserts_stripped_with_flag(self, stripped):
+        assert ASSERT_MACRO.search(stripped) is None
+        assert "n must be non-negative" not in stripped
+        assert "part exceeds total" not in stripped
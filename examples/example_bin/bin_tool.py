@@ -14,6 +14,8 @@ def main():
     f.add_argument("binstr")
     b = subs.add_parser("bits")
     b.add_argument("num", type=int)
+    by = subs.add_parser("byte")
+    by.add_argument("num", type=int)
 
     args = parser.parse_args()
     if args.cmd == "tobin":
@@ -27,6 +29,8 @@ def main():
             count = count + 1
             n = n // 2
         print(count)
+    elif args.cmd == "byte":
+        print(format(args.num, "08b"))
 
 
 if __name__ == "__main__":
@@ -0,0 +1,55 @@
+"""Tests for bin_tool.py"""
+
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "bin_tool.py"
+
+GOLDEN = GoldenCli(SCRIPT)
+run = GOLDEN.run
+
+
+class TestFormat:
+    def test_tobin(self):
+        stdout, _, code = run(["tobin", "5"])
+        assert code == 0
+        assert stdout.strip() == "101"
+
+    def test_tobin_zero(self):
+        stdout, _, _ = run(["tobin", "0"])
+        assert stdout.strip() == "0"
+
+    def test_byte_zero_padded(self):
+        stdout, _, _ = run(["byte", "5"])
+        assert stdout.strip() == "00000101"
+
+    def test_byte_wider_than_width(self):
+        stdout, _, _ = run(["byte", "300"])
+        assert stdout.strip() == "100101100"
+
+
+class TestParse:
+    def test_frombin(self):
+        stdout, _, _ = run(["frombin", "101"])
+        assert stdout.strip() == "5"
+
+    def test_bits(self):
+        stdout, _, _ = run(["bits", "8"])
+        assert stdout.strip() == "4"
+
+
+class TestGolden:
+    """format(n, spec) must lower to the matching Rust radix format"""
+
+    @pytest.mark.parametrize(
+        "argv,stdout",
+        [
+            (["tobin", "5"], "101\n"),
+            (["byte", "5"], "00000101\n"),
+        ],
+    )
+    def test_golden(self, argv, stdout):
+        GOLDEN.check(argv, stdout)
@@ -0,0 +1,30 @@
+#!/usr/bin/env python3
+"""Bool Optional Example - argparse.BooleanOptionalAction CLI.
+
+Each option gets a `--name` / `--no-name` pair; the last one given wins
+and the default applies when neither appears.
+"""
+
+import argparse
+
+
+def main():
+    parser = argparse.ArgumentParser(description="Negatable flag tool")
+    parser.add_argument(
+        "--color", action=argparse.BooleanOptionalAction, default=True, help="Colorize output"
+    )
+    parser.add_argument(
+        "--strict", action=argparse.BooleanOptionalAction, default=False, help="Fail on warnings"
+    )
+    parser.add_argument("name")
+
+    args = parser.parse_args()
+    print(f"color={args.color} strict={args.strict}")
+    if args.color:
+        print(f"[{args.name}]")
+    else:
+        print(args.name)
+
+
+if __name__ == "__main__":
+    main()
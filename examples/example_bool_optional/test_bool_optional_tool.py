@@ -0,0 +1,46 @@
+"""Tests for bool_optional_tool.py"""
+
+from pathlib import Path
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "bool_optional_tool.py"
+
+run = GoldenCli(SCRIPT).run
+
+
+class TestDefaults:
+    def test_defaults(self):
+        stdout, _, code = run(["build"])
+        assert code == 0
+        assert stdout.splitlines() == ["color=True strict=False", "[build]"]
+
+
+class TestNegatable:
+    def test_positive_flag(self):
+        stdout, _, code = run(["--strict", "build"])
+        assert code == 0
+        assert "strict=True" in stdout
+
+    def test_negative_flag(self):
+        stdout, _, code = run(["--no-color", "build"])
+        assert code == 0
+        assert stdout.splitlines() == ["color=False strict=False", "build"]
+
+    def test_negate_default_false(self):
+        stdout, _, _ = run(["--no-strict", "build"])
+        assert "strict=False" in stdout
+
+    def test_last_flag_wins(self):
+        stdout, _, _ = run(["--no-color", "--color", "--strict", "--no-strict", "build"])
+        assert "color=True strict=False" in stdout
+
+    def test_help_lists_both_forms(self):
+        stdout, _, code = run(["--help"])
+        assert code == 0
+        assert "--color, --no-color" in stdout
+        assert "--strict, --no-strict" in stdout
+
+    def test_value_not_accepted(self):
+        _, _, code = run(["--color=yes", "build"])
+        assert code == 2
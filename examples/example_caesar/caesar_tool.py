@@ -0,0 +1,81 @@
+#!/usr/bin/env python3
+"""Caesar Example - Letter shifting with ord/chr arithmetic CLI.
+
+Each letter is shifted with `chr((ord(c) - ord("a") + k) % 26 + ord("a"))`.
+Python's `%` is non-negative for a positive modulus, so negative shifts
+wrap backwards correctly. Non-letters pass through unchanged.
+
+Examples:
+    >>> shift_char("a", 3)
+    'd'
+    >>> encrypt("xyz", 3)
+    'abc'
+    >>> decrypt("abc", 3)
+    'xyz'
+"""
+
+import argparse
+
+
+def shift_char(c: str, k: int) -> str:
+    """Shift one character by k places, preserving case.
+
+    >>> shift_char("Z", 1)
+    'A'
+    >>> shift_char("a", -1)
+    'z'
+    >>> shift_char("!", 5)
+    '!'
+    """
+    if "a" <= c <= "z":
+        return chr((ord(c) - ord("a") + k) % 26 + ord("a"))
+    if "A" <= c <= "Z":
+        return chr((ord(c) - ord("A") + k) % 26 + ord("A"))
+    return c
+
+
+def encrypt(text: str, k: int) -> str:
+    """Shift every letter forward by k.
+
+    >>> encrypt("Hello, World!", 13)
+    'Uryyb, Jbeyq!'
+    """
+    result = ""
+    for c in text:
+        result = result + shift_char(c, k)
+    return result
+
+
+def decrypt(text: str, k: int) -> str:
+    """Undo encrypt.
+
+    >>> decrypt(encrypt("round trip", 7), 7)
+    'round trip'
+    """
+    return encrypt(text, -k)
+
+
+def main():
+    parser = argparse.ArgumentParser(description="Caesar cipher tool")
+    subs = parser.add_subparsers(dest="cmd", required=True)
+
+    e = subs.add_parser("encrypt")
+    e.add_argument("text")
+    e.add_argument("shift", type=int)
+    d = subs.add_parser("decrypt")
+    d.add_argument("text")
+    d.add_argument("shift", type=int)
+    r = subs.add_parser("rot13")
+    r.add_argument("text")
+
+    args = parser.parse_args()
+    if args.cmd == "encrypt":
+        print(encrypt(args.text, args.shift))
+    elif args.cmd == "decrypt":
+        print(decrypt(args.text, args.shift))
+    elif args.cmd == "rot13":
+        print(encrypt(args.text, 13))
+
+
+if __name__ == "__main__":
+    main()
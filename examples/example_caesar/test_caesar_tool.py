@@ -0,0 +1,44 @@
+"""Tests for caesar_tool.py"""
+
+from pathlib import Path
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "caesar_tool.py"
+
+run = GoldenCli(SCRIPT).run
+
+
+class TestEncrypt:
+    def test_shift(self):
+        stdout, _, code = run(["encrypt", "abc", "1"])
+        assert code == 0
+        assert stdout.strip() == "bcd"
+
+    def test_wraparound(self):
+        stdout, _, _ = run(["encrypt", "xyz", "3"])
+        assert stdout.strip() == "abc"
+
+    def test_uppercase_wraparound(self):
+        stdout, _, _ = run(["encrypt", "XYZ", "3"])
+        assert stdout.strip() == "ABC"
+
+    def test_large_shift(self):
+        stdout, _, _ = run(["encrypt", "abc", "27"])
+        assert stdout.strip() == "bcd"
+
+    def test_punctuation_unchanged(self):
+        stdout, _, _ = run(["encrypt", "a-b c!", "1"])
+        assert stdout.strip() == "b-c d!"
+
+
+class TestDecrypt:
+    def test_negative_wraparound(self):
+        stdout, _, _ = run(["decrypt", "abc", "3"])
+        assert stdout.strip() == "xyz"
+
+    def test_rot13_is_involution(self):
+        once, _, _ = run(["rot13", "Hello"])
+        assert once.strip() == "Uryyb"
+        twice, _, _ = run(["rot13", once.strip()])
+        assert twice.strip() == "Hello"
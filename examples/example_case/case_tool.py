@@ -0,0 +1,41 @@
+#!/usr/bin/env python3
+"""Case Example - upper/lower/title case conversion CLI.
+
+`title()` capitalizes the first letter of every run of letters and
+lowercases the rest. A word boundary is any non-letter, not just
+whitespace, so "rock-n-roll" becomes "Rock-N-Roll".
+
+Examples:
+    >>> "Hello World".upper()
+    'HELLO WORLD'
+    >>> "Hello World".lower()
+    'hello world'
+    >>> "hELLO wORLD".title()
+    'Hello World'
+"""
+
+import argparse
+
+
+def main():
+    parser = argparse.ArgumentParser(description="Case conversion tool")
+    subs = parser.add_subparsers(dest="cmd", required=True)
+
+    u = subs.add_parser("upper")
+    u.add_argument("text")
+    lo = subs.add_parser("lower")
+    lo.add_argument("text")
+    t = subs.add_parser("title")
+    t.add_argument("text")
+
+    args = parser.parse_args()
+    if args.cmd == "upper":
+        print(args.text.upper())
+    elif args.cmd == "lower":
+        print(args.text.lower())
+    elif args.cmd == "title":
+        print(args.text.title())
+
+
+if __name__ == "__main__":
+    main()
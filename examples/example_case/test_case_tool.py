@@ -0,0 +1,71 @@
+"""Tests for case_tool.py"""
+
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "case_tool.py"
+
+GOLDEN = GoldenCli(SCRIPT)
+run = GOLDEN.run
+
+
+class TestUpper:
+    def test_mixed(self):
+        stdout, _, code = run(["upper", "Hello, World 42"])
+        assert code == 0
+        assert stdout == "HELLO, WORLD 42\n"
+
+    def test_non_ascii(self):
+        stdout, _, _ = run(["upper", "straße"])
+        assert stdout == "STRASSE\n"
+
+
+class TestLower:
+    def test_mixed(self):
+        stdout, _, code = run(["lower", "Hello, World 42"])
+        assert code == 0
+        assert stdout == "hello, world 42\n"
+
+    def test_non_ascii(self):
+        stdout, _, _ = run(["lower", "ÉCOLE"])
+        assert stdout == "école\n"
+
+
+class TestTitle:
+    def test_words(self):
+        stdout, _, code = run(["title", "the quick  brown fox"])
+        assert code == 0
+        assert stdout == "The Quick  Brown Fox\n"
+
+    def test_lowercases_rest(self):
+        stdout, _, _ = run(["title", "hELLO wORLD"])
+        assert stdout == "Hello World\n"
+
+    def test_non_letter_boundaries(self):
+        stdout, _, _ = run(["title", "rock-n-roll 2nd"])
+        assert stdout == "Rock-N-Roll 2Nd\n"
+
+    def test_apostrophe(self):
+        stdout, _, _ = run(["title", "they're"])
+        assert stdout == "They'Re\n"
+
+
+class TestGolden:
+    """upper/lower/title must lower to the matching Rust string transforms."""
+
+    @pytest.mark.parametrize(
+        "argv,stdout",
+        [
+            (["upper", "Hello, World 42"], "HELLO, WORLD 42\n"),
+            (["upper", "straße"], "STRASSE\n"),
+            (["lower", "ÉCOLE"], "école\n"),
+            (["title", "the quick  brown fox"], "The Quick  Brown Fox\n"),
+            (["title", "hELLO wORLD"], "Hello World\n"),
+            (["title", "rock-n-roll"], "Rock-N-Roll\n"),
+        ],
+    )
+    def test_golden(self, argv, stdout):
+        GOLDEN.check(argv, stdout)
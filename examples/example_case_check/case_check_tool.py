@@ -0,0 +1,52 @@
+#!/usr/bin/env python3
+"""Case Check Example - isupper/islower/istitle CLI.
+
+Non-cased characters (digits, punctuation, spaces) are ignored, but at
+least one cased character must be present, so `""` and `"123"` are
+neither upper, lower nor title case.
+
+Examples:
+    >>> "HTTP/2".isupper()
+    True
+    >>> "123".islower()
+    False
+    >>> "Hello World".istitle()
+    True
+"""
+
+import argparse
+
+
+def main():
+    parser = argparse.ArgumentParser(description="Case classification tool")
+    subs = parser.add_subparsers(dest="cmd", required=True)
+
+    u = subs.add_parser("upper")
+    u.add_argument("text")
+    lo = subs.add_parser("lower")
+    lo.add_argument("text")
+    t = subs.add_parser("title")
+    t.add_argument("text")
+    c = subs.add_parser("classify")
+    c.add_argument("text")
+
+    args = parser.parse_args()
+    if args.cmd == "upper":
+        print(args.text.isupper())
+    elif args.cmd == "lower":
+        print(args.text.islower())
+    elif args.cmd == "title":
+        print(args.text.istitle())
+    elif args.cmd == "classify":
+        if args.text.isupper():
+            print("upper")
+        elif args.text.islower():
+            print("lower")
+        elif args.text.istitle():
+            print("title")
+        else:
+            print("mixed")
+
+
+if __name__ == "__main__":
+    main()
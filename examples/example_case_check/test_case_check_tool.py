@@ -0,0 +1,58 @@
+"""Tests for case_check_tool.py"""
+
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "case_check_tool.py"
+
+run = GoldenCli(SCRIPT).run
+
+
+@pytest.mark.parametrize(
+    "cmd,text,expected",
+    [
+        ("upper", "ABC", "True"),
+        ("upper", "ABC 123!", "True"),
+        ("upper", "AbC", "False"),
+        ("upper", "123", "False"),
+        ("upper", "", "False"),
+        ("lower", "abc", "True"),
+        ("lower", "abc-42", "True"),
+        ("lower", "aBc", "False"),
+        ("lower", "...", "False"),
+        ("title", "Hello World", "True"),
+        ("title", "Hello world", "False"),
+        ("title", "HELLO", "False"),
+        ("title", "1st Place", "False"),
+        ("title", "", "False"),
+    ],
+)
+def test_predicate(cmd, text, expected):
+    stdout, _, code = run([cmd, text])
+    assert code == 0
+    assert stdout.strip() == expected
+
+
+class TestClassify:
+    def test_all_upper(self):
+        stdout, _, _ = run(["classify", "NASA"])
+        assert stdout.strip() == "upper"
+
+    def test_all_lower(self):
+        stdout, _, _ = run(["classify", "rust"])
+        assert stdout.strip() == "lower"
+
+    def test_title(self):
+        stdout, _, _ = run(["classify", "Guido Van Rossum"])
+        assert stdout.strip() == "title"
+
+    def test_mixed_case(self):
+        stdout, _, _ = run(["classify", "iPhone"])
+        assert stdout.strip() == "mixed"
+
+    def test_no_cased_characters(self):
+        stdout, _, _ = run(["classify", "2024-01-01"])
+        assert stdout.strip() == "mixed"
@@ -0,0 +1,62 @@
+#!/usr/bin/env python3
+"""Casefold Dict Example - Case-insensitive lookup CLI.
+
+Keys are normalized with `str.casefold()` on both insert and lookup, so
+"Content-Type", "content-type" and "CONTENT-TYPE" are the same entry.
+`casefold` is stricter than `lower`: "Straße" and "STRASSE" also match.
+
+Examples:
+    >>> d = {}
+    >>> ci_put(d, "Accept", "json")
+    >>> ci_get(d, "ACCEPT", "none")
+    'json'
+"""
+
+import argparse
+
+
+def ci_put(d: dict[str, str], key: str, value: str) -> None:
+    """Insert under the casefolded key, replacing any case variant.
+
+    >>> d = {}
+    >>> ci_put(d, "Host", "a")
+    >>> ci_put(d, "HOST", "b")
+    >>> d
+    {'host': 'b'}
+    """
+    d[key.casefold()] = value
+
+
+def ci_get(d: dict[str, str], key: str, default: str) -> str:
+    """Look up by casefolded key, returning default when absent.
+
+    >>> ci_get({"host": "a"}, "Port", "-")
+    '-'
+    """
+    return d.get(key.casefold(), default)
+
+
+def main():
+    parser = argparse.ArgumentParser(description="Case-insensitive lookup tool")
+    subs = parser.add_subparsers(dest="cmd", required=True)
+
+    g = subs.add_parser("get")
+    g.add_argument("key")
+    g.add_argument("pairs", nargs="+", help="KEY=VALUE entries")
+    c = subs.add_parser("count")
+    c.add_argument("pairs", nargs="+", help="KEY=VALUE entries")
+
+    args = parser.parse_args()
+    headers: dict[str, str] = {}
+    for pair in args.pairs:
+        key, _, value = pair.partition("=")
+        ci_put(headers, key, value)
+
+    if args.cmd == "get":
+        print(ci_get(headers, args.key, "(missing)"))
+    elif args.cmd == "count":
+        print(len(headers))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,39 @@
+"""Tests for casefold_dict_tool.py"""
+
+from pathlib import Path
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "casefold_dict_tool.py"
+
+run = GoldenCli(SCRIPT).run
+
+
+class TestLookup:
+    def test_mixed_case_insert_lower_lookup(self):
+        stdout, _, code = run(["get", "content-type", "Content-Type=text/html"])
+        assert code == 0
+        assert stdout.strip() == "text/html"
+
+    def test_upper_lookup(self):
+        stdout, _, _ = run(["get", "ACCEPT", "Accept=json", "Host=x"])
+        assert stdout.strip() == "json"
+
+    def test_missing_key(self):
+        stdout, _, code = run(["get", "Port", "Host=x"])
+        assert code == 0
+        assert stdout.strip() == "(missing)"
+
+    def test_casefold_beyond_lower(self):
+        stdout, _, _ = run(["get", "STRASSE", "Straße=berlin"])
+        assert stdout.strip() == "berlin"
+
+
+class TestInsert:
+    def test_case_variants_collapse(self):
+        stdout, _, _ = run(["count", "Host=a", "HOST=b", "host=c"])
+        assert stdout.strip() == "1"
+
+    def test_last_insert_wins(self):
+        stdout, _, _ = run(["get", "host", "Host=a", "HOST=b"])
+        assert stdout.strip() == "b"
@@ -0,0 +1,87 @@
+#!/usr/bin/env python3
+"""Combinatorics Example - Factorial, combinations and permutations CLI.
+
+Results grow past 64 bits quickly (comb(100, 50) needs 97 bits), so the
+integer arithmetic must not wrap. Negative arguments raise ValueError.
+
+Examples:
+    >>> factorial(5)
+    120
+    >>> comb(52, 5)
+    2598960
+    >>> perm(5, 2)
+    20
+"""
+
+import argparse
+import math
+import sys
+
+
+def factorial(n: int) -> int:
+    """Return n!, rejecting negative n.
+
+    >>> factorial(0)
+    1
+    >>> factorial(25)
+    15511210043330985984000000
+    >>> factorial(-1)
+    Traceback (most recent call last):
+        ...
+    ValueError: factorial() not defined for negative values
+    """
+    return math.factorial(n)
+
+
+def comb(n: int, k: int) -> int:
+    """Ways to choose k items from n without order.
+
+    >>> comb(5, 0)
+    1
+    >>> comb(3, 5)
+    0
+    >>> comb(100, 50)
+    100891344545564193334812497256
+    """
+    return math.comb(n, k)
+
+
+def perm(n: int, k: int) -> int:
+    """Ways to choose k items from n in order.
+
+    >>> perm(4, 4)
+    24
+    >>> perm(3, 5)
+    0
+    """
+    return math.perm(n, k)
+
+
+def main():
+    parser = argparse.ArgumentParser(description="Combinatorics tool")
+    subs = parser.add_subparsers(dest="cmd", required=True)
+
+    f = subs.add_parser("factorial")
+    f.add_argument("n", type=int)
+    c = subs.add_parser("comb")
+    c.add_argument("n", type=int)
+    c.add_argument("k", type=int)
+    p = subs.add_parser("perm")
+    p.add_argument("n", type=int)
+    p.add_argument("k", type=int)
+
+    args = parser.parse_args()
+    try:
+        if args.cmd == "factorial":
+            print(factorial(args.n))
+        elif args.cmd == "comb":
+            print(comb(args.n, args.k))
+        elif args.cmd == "perm":
+            print(perm(args.n, args.k))
+    except ValueError as e:
+        print(f"Error: {e}", file=sys.stderr)
+        sys.exit(1)
+
+
+if __name__ == "__main__":
+    main()
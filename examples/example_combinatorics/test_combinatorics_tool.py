@@ -0,0 +1,105 @@
+"""Tests for combinatorics_tool.py"""
+
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "combinatorics_tool.py"
+
+GOLDEN = GoldenCli(SCRIPT)
+run = GOLDEN.run
+
+# The same source built with Python int mapped to BigInt
+BIGINT = GoldenCli(SCRIPT, int_mode="bigint")
+
+
+class TestFactorial:
+    def test_small(self):
+        stdout, _, code = run(["factorial", "5"])
+        assert code == 0
+        assert stdout.strip() == "120"
+
+    def test_zero(self):
+        stdout, _, _ = run(["factorial", "0"])
+        assert stdout.strip() == "1"
+
+    def test_past_u64(self):
+        stdout, _, _ = run(["factorial", "25"])
+        assert stdout.strip() == "15511210043330985984000000"
+
+    def test_thirty(self):
+        stdout, _, _ = run(["factorial", "30"])
+        assert stdout.strip() == "265252859812191058636308480000000"
+
+    def test_negative(self):
+        _, stderr, code = run(["factorial", "-3"])
+        assert code == 1
+        assert "Error: factorial() not defined for negative values" in stderr
+
+
+class TestComb:
+    def test_poker_hands(self):
+        stdout, _, code = run(["comb", "52", "5"])
+        assert code == 0
+        assert stdout.strip() == "2598960"
+
+    def test_k_greater_than_n(self):
+        stdout, _, _ = run(["comb", "3", "5"])
+        assert stdout.strip() == "0"
+
+    def test_past_u64(self):
+        stdout, _, _ = run(["comb", "100", "50"])
+        assert stdout.strip() == "100891344545564193334812497256"
+
+    def test_negative(self):
+        _, stderr, code = run(["comb", "5", "-1"])
+        assert code == 1
+        assert "Error:" in stderr
+
+
+class TestPerm:
+    def test_perm(self):
+        stdout, _, _ = run(["perm", "5", "2"])
+        assert stdout.strip() == "20"
+
+    def test_full(self):
+        stdout, _, _ = run(["perm", "4", "4"])
+        assert stdout.strip() == "24"
+
+    def test_negative(self):
+        _, _, code = run(["perm", "-1", "2"])
+        assert code == 1
+
+
+class TestGolden:
+    """Combinatorics results must not wrap at 64 bits."""
+
+    @pytest.mark.parametrize(
+        "argv,stdout",
+        [
+            (["factorial", "20"], "2432902008176640000\n"),
+            (["factorial", "21"], "51090942171709440000\n"),
+            (["comb", "52", "5"], "2598960\n"),
+            (["comb", "100", "50"], "100891344545564193334812497256\n"),
+            (["perm", "10", "3"], "720\n"),
+        ],
+    )
+    def test_golden(self, argv, stdout):
+        GOLDEN.check(argv, stdout)
+
+
+class TestGoldenBigint:
+    """With --int=bigint nothing overflows, however large the result."""
+
+    @pytest.mark.parametrize(
+        "argv,stdout",
+        [
+            (["factorial", "30"], "265252859812191058636308480000000\n"),
+            (["comb", "100", "50"], "100891344545564193334812497256\n"),
+            (["perm", "30", "30"], "265252859812191058636308480000000\n"),
+        ],
+    )
+    def test_golden(self, argv, stdout):
+        BIGINT.check(argv, stdout)
@@ -0,0 +1,68 @@
+#!/usr/bin/env python3
+"""Comments Example - Source comments and docstrings CLI.
+
+Every function carries a docstring and inline `#` comments so that a
+comment-preserving transpile can be audited line by line against the
+Python source.
+
+Examples:
+    >>> celsius_to_fahrenheit(100)
+    212
+    >>> clamp(15, 0, 10)
+    10
+"""
+
+import argparse
+
+# Freezing point offset between the two scales.
+FAHRENHEIT_OFFSET = 32
+
+
+def celsius_to_fahrenheit(c: int) -> int:
+    """Convert whole degrees Celsius to whole degrees Fahrenheit.
+
+    >>> celsius_to_fahrenheit(0)
+    32
+    >>> celsius_to_fahrenheit(-40)
+    -40
+    """
+    # Scale first, then shift by the freezing point offset.
+    return c * 9 // 5 + FAHRENHEIT_OFFSET
+
+
+def clamp(value: int, low: int, high: int) -> int:
+    """Clamp value into the inclusive range [low, high].
+
+    >>> clamp(-3, 0, 10)
+    0
+    >>> clamp(5, 0, 10)
+    5
+    """
+    if value < low:
+        return low  # below range: pin to the lower bound
+    if value > high:
+        return high  # above range: pin to the upper bound
+    return value
+
+
+def main():
+    parser = argparse.ArgumentParser(description="Commented tool")
+    subs = parser.add_subparsers(dest="cmd", required=True)
+
+    f = subs.add_parser("fahrenheit")
+    f.add_argument("celsius", type=int)
+    c = subs.add_parser("clamp")
+    c.add_argument("value", type=int)
+    c.add_argument("low", type=int)
+    c.add_argument("high", type=int)
+
+    args = parser.parse_args()
+    # Dispatch on the selected subcommand.
+    if args.cmd == "fahrenheit":
+        print(celsius_to_fahrenheit(args.celsius))
+    elif args.cmd == "clamp":
+        print(clamp(args.value, args.low, args.high))
+
+
+if __name__ == "__main__":
+    main()
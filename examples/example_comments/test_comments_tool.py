@@ -0,0 +1,57 @@
+"""Tests for comments_tool.py
+
+The TestEmitComments cases exercise depyler's `--emit-comments` mode and
+are skipped when depyler is not installed or does not support the flag.
+"""
+
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "comments_tool.py"
+
+GOLDEN = GoldenCli(SCRIPT)
+run = GOLDEN.run
+
+
+@pytest.fixture(scope="module")
+def commented():
+    """Rust source with --emit-comments, transpiled once per module."""
+    return GOLDEN.rust_source_or_skip("--emit-comments")
+
+
+@pytest.fixture(scope="module")
+def plain():
+    """Rust source in the default mode, transpiled once per module."""
+    return GOLDEN.rust_source_or_skip()
+
+
+class TestCli:
+    def test_fahrenheit(self):
+        stdout, _, code = run(["fahrenheit", "100"])
+        assert code == 0
+        assert stdout.strip() == "212"
+
+    def test_clamp_low(self):
+        stdout, _, _ = run(["clamp", "-5", "0", "10"])
+        assert stdout.strip() == "0"
+
+    def test_clamp_high(self):
+        stdout, _, _ = run(["clamp", "50", "0", "10"])
+        assert stdout.strip() == "10"
+
+
+class TestEmitComments:
+    def test_line_comment_preserved(self, commented):
+        assert "// Scale first, then shift by the freezing point offset." in commented
+
+    def test_trailing_comment_preserved(self, commented):
+        assert "// below range: pin to the lower bound" in commented
+
+    def test_docstring_preserved(self, commented):
+        assert "Clamp value into the inclusive range [low, high]." in commented
+
+    def test_comments_dropped_by_default(self, plain):
+        assert "Scale first, then shift" not in plain
@@ -0,0 +1,74 @@
+#!/usr/bin/env python3
+"""Comprehension Example - Single-generator list comprehensions CLI.
+
+Each subcommand is one `[expr for x in xs if cond]` comprehension, with
+the filter optional. They replace the index-and-accumulate loops used
+by example_filter and example_enumerate.
+
+Examples:
+    >>> squares([1, 2, 3])
+    [1, 4, 9]
+    >>> evens([1, 2, 3, 4])
+    [2, 4]
+    >>> lengths(["a", "bb", ""], 1)
+    [1, 2]
+"""
+
+import argparse
+
+
+def squares(nums: list[int]) -> list[int]:
+    """Square of every number.
+
+    >>> squares([])
+    []
+    >>> squares([-3])
+    [9]
+    """
+    return [n * n for n in nums]
+
+
+def evens(nums: list[int]) -> list[int]:
+    """Only the even numbers, in order.
+
+    >>> evens([1, 3])
+    []
+    >>> evens([-2, 0, 5])
+    [-2, 0]
+    """
+    return [n for n in nums if n % 2 == 0]
+
+
+def lengths(words: list[str], min_len: int) -> list[int]:
+    """Lengths of the words at least min_len characters long.
+
+    >>> lengths(["héllo", "a"], 2)
+    [5]
+    """
+    return [len(w) for w in words if len(w) >= min_len]
+
+
+def main():
+    parser = argparse.ArgumentParser(description="List comprehension tool")
+    subs = parser.add_subparsers(dest="cmd", required=True)
+
+    sq = subs.add_parser("squares")
+    sq.add_argument("nums", type=int, nargs="*")
+    ev = subs.add_parser("evens")
+    ev.add_argument("nums", type=int, nargs="*")
+    ln = subs.add_parser("lengths")
+    ln.add_argument("words", nargs="*")
+    ln.add_argument("--min", type=int, default=1)
+
+    args = parser.parse_args()
+    if args.cmd == "squares":
+        result = squares(args.nums)
+    elif args.cmd == "evens":
+        result = evens(args.nums)
+    else:
+        result = lengths(args.words, args.min)
+    print(" ".join([str(x) for x in result]))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,65 @@
+"""Tests for comprehension_tool.py"""
+
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "comprehension_tool.py"
+
+GOLDEN = GoldenCli(SCRIPT)
+run = GOLDEN.run
+
+
+class TestMap:
+    def test_squares(self):
+        stdout, _, code = run(["squares", "1", "-2", "3"])
+        assert code == 0
+        assert stdout == "1 4 9\n"
+
+    def test_empty(self):
+        stdout, _, _ = run(["squares"])
+        assert stdout == "\n"
+
+
+class TestFilter:
+    def test_evens(self):
+        stdout, _, code = run(["evens", "1", "2", "3", "4", "-6"])
+        assert code == 0
+        assert stdout == "2 4 -6\n"
+
+    def test_none_match(self):
+        stdout, _, _ = run(["evens", "1", "3"])
+        assert stdout == "\n"
+
+
+class TestMapAndFilter:
+    def test_lengths(self):
+        stdout, _, _ = run(["lengths", "a", "bb", "ccc", "--min", "2"])
+        assert stdout == "2 3\n"
+
+    def test_default_min_drops_empty(self):
+        stdout, _, _ = run(["lengths", "x", "", "yz"])
+        assert stdout == "1 2\n"
+
+    def test_multibyte_counts_chars(self):
+        stdout, _, _ = run(["lengths", "héllo", "ñ"])
+        assert stdout == "5 1\n"
+
+
+class TestGolden:
+    """Comprehensions must lower to iter().filter().map().collect() chains."""
+
+    @pytest.mark.parametrize(
+        "argv,stdout",
+        [
+            (["squares", "1", "-2", "3"], "1 4 9\n"),
+            (["evens", "1", "2", "3", "4"], "2 4\n"),
+            (["evens", "1", "3"], "\n"),
+            (["lengths", "a", "bb", "ccc", "--min", "2"], "2 3\n"),
+            (["lengths", "héllo"], "5\n"),
+        ],
+    )
+    def test_golden(self, argv, stdout):
+        GOLDEN.check(argv, stdout)
@@ -0,0 +1,72 @@
+#!/usr/bin/env python3
+"""Contains Example - `in` for substrings and list membership CLI.
+
+On strings, `in` tests for a substring, so "" is in every string. On
+lists, it tests for an equal element, so "ell" is not in ["hello"].
+
+Examples:
+    >>> has_substring("hello", "ell")
+    True
+    >>> has_item(["hello"], "ell")
+    False
+"""
+
+import argparse
+
+
+def has_substring(text: str, sub: str) -> bool:
+    """Whether sub occurs anywhere in text.
+
+    >>> has_substring("abc", "")
+    True
+    >>> has_substring("abc", "abcd")
+    False
+    """
+    return sub in text
+
+
+def has_item(items: list[str], item: str) -> bool:
+    """Whether item equals some element of items.
+
+    >>> has_item(["a", "b"], "b")
+    True
+    >>> has_item([], "")
+    False
+    """
+    return item in items
+
+
+def missing(items: list[str], wanted: list[str]) -> list[str]:
+    """Wanted entries absent from items, in order.
+
+    >>> missing(["a", "b"], ["b", "c", "d"])
+    ['c', 'd']
+    """
+    return [w for w in wanted if w not in items]
+
+
+def main():
+    parser = argparse.ArgumentParser(description="Membership test tool")
+    subs = parser.add_subparsers(dest="cmd", required=True)
+
+    s = subs.add_parser("substr")
+    s.add_argument("text")
+    s.add_argument("sub")
+    i = subs.add_parser("item")
+    i.add_argument("item")
+    i.add_argument("items", nargs="*")
+    m = subs.add_parser("missing")
+    m.add_argument("--have", nargs="*", default=[])
+    m.add_argument("--want", nargs="+", required=True)
+
+    args = parser.parse_args()
+    if args.cmd == "substr":
+        print(has_substring(args.text, args.sub))
+    elif args.cmd == "item":
+        print(has_item(args.items, args.item))
+    elif args.cmd == "missing":
+        print(" ".join(missing(args.have, args.want)))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,72 @@
+"""Tests for contains_tool.py"""
+
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "contains_tool.py"
+
+GOLDEN = GoldenCli(SCRIPT)
+run = GOLDEN.run
+
+
+class TestSubstring:
+    @pytest.mark.parametrize(
+        "text,sub,expected",
+        [
+            ("hello world", "lo w", "True"),
+            ("hello", "ell", "True"),
+            ("hello", "Hell", "False"),
+            ("hello", "", "True"),
+            ("", "a", "False"),
+            ("naïve", "ïv", "True"),
+        ],
+    )
+    def test_substr(self, text, sub, expected):
+        stdout, _, code = run(["substr", text, sub])
+        assert code == 0
+        assert stdout.strip() == expected
+
+
+class TestItem:
+    def test_present(self):
+        stdout, _, _ = run(["item", "b", "a", "b", "c"])
+        assert stdout.strip() == "True"
+
+    def test_whole_element_only(self):
+        stdout, _, _ = run(["item", "ell", "hello", "bell"])
+        assert stdout.strip() == "False"
+
+    def test_empty_list(self):
+        stdout, _, _ = run(["item", "a"])
+        assert stdout.strip() == "False"
+
+
+class TestMissing:
+    def test_not_in(self):
+        stdout, _, _ = run(["missing", "--have", "a", "b", "--want", "b", "c", "d"])
+        assert stdout.strip() == "c d"
+
+    def test_nothing_missing(self):
+        stdout, _, _ = run(["missing", "--have", "a", "--want", "a"])
+        assert stdout == "\n"
+
+
+class TestGolden:
+    """`in` must pick contains() on str or on Vec from the operand types."""
+
+    @pytest.mark.parametrize(
+        "argv,stdout",
+        [
+            (["substr", "hello", "ell"], "True\n"),
+            (["substr", "hello", ""], "True\n"),
+            (["substr", "hello", "Hell"], "False\n"),
+            (["item", "b", "a", "b", "c"], "True\n"),
+            (["item", "ell", "hello"], "False\n"),
+            (["missing", "--have", "a", "--want", "a", "z"], "z\n"),
+        ],
+    )
+    def test_golden(self, argv, stdout):
+        GOLDEN.check(argv, stdout)
@@ -1,7 +1,50 @@
 #!/usr/bin/env python3
-"""Copy Example - Copy operations CLI."""
+"""Copy Example - Copy operations CLI.
+
+`copy.copy` duplicates only the outer list, so inner rows are still
+shared with the original. `copy.deepcopy` clones the nested rows too.
+
+Examples:
+    >>> shallow_edit([[1, 2], [3, 4]])
+    ([[0, 2], [3, 4]], [[0, 2], [3, 4]])
+    >>> deep_edit([[1, 2], [3, 4]])
+    ([[1, 2], [3, 4]], [[0, 2], [3, 4]])
+"""
 
 import argparse
+import copy
+
+
+def parse_grid(rows: list[str]) -> list[list[int]]:
+    """Parse comma-separated rows into a list of lists.
+
+    >>> parse_grid(["1,2", "3"])
+    [[1, 2], [3]]
+    """
+    return [[int(x) for x in row.split(",")] for row in rows]
+
+
+def format_grid(grid: list[list[int]]) -> str:
+    """Render rows as space-separated comma lists.
+
+    >>> format_grid([[1, 2], [3]])
+    '1,2 3'
+    """
+    return " ".join(",".join(str(x) for x in row) for row in grid)
+
+
+def shallow_edit(grid: list[list[int]]) -> tuple[list[list[int]], list[list[int]]]:
+    """Zero the first cell of a shallow copy; the original sees the change."""
+    dup = copy.copy(grid)
+    dup[0][0] = 0
+    return grid, dup
+
+
+def deep_edit(grid: list[list[int]]) -> tuple[list[list[int]], list[list[int]]]:
+    """Zero the first cell of a deep copy; the original is untouched."""
+    dup = copy.deepcopy(grid)
+    dup[0][0] = 0
+    return grid, dup
 
 
 def main():
@@ -15,6 +58,10 @@ def main():
     du = subs.add_parser("dup")
     du.add_argument("value")
     du.add_argument("times", type=int)
+    sg = subs.add_parser("shallow-grid")
+    sg.add_argument("rows", nargs="+")
+    dg = subs.add_parser("deep-grid")
+    dg.add_argument("rows", nargs="+")
 
     args = parser.parse_args()
     if args.cmd == "shallow":
@@ -26,6 +73,14 @@ def main():
         while i < args.times:
             print(args.value)
             i = i + 1
+    elif args.cmd == "shallow-grid":
+        original, dup = shallow_edit(parse_grid(args.rows))
+        print(f"original: {format_grid(original)}")
+        print(f"copy: {format_grid(dup)}")
+    elif args.cmd == "deep-grid":
+        original, dup = deep_edit(parse_grid(args.rows))
+        print(f"original: {format_grid(original)}")
+        print(f"copy: {format_grid(dup)}")
 
 
 if __name__ == "__main__":
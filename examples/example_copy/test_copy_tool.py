@@ -0,0 +1,30 @@
+"""Tests for copy_tool.py"""
+
+from pathlib import Path
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "copy_tool.py"
+
+run = GoldenCli(SCRIPT).run
+
+
+class TestValue:
+    def test_dup(self):
+        stdout, _, code = run(["dup", "x", "3"])
+        assert code == 0
+        assert stdout == "x\nx\nx\n"
+
+
+class TestDeepCopy:
+    def test_mutating_copy_leaves_original(self):
+        stdout, _, code = run(["deep-grid", "1,2", "3,4"])
+        assert code == 0
+        assert stdout == "original: 1,2 3,4\ncopy: 0,2 3,4\n"
+
+
+class TestShallowCopy:
+    def test_inner_rows_are_shared(self):
+        stdout, _, code = run(["shallow-grid", "1,2", "3,4"])
+        assert code == 0
+        assert stdout == "original: 0,2 3,4\ncopy: 0,2 3,4\n"
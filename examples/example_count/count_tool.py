@@ -15,6 +15,9 @@ def main():
     v.add_argument("text")
     co = subs.add_parser("consonants")
     co.add_argument("text")
+    ov = subs.add_parser("overlap")
+    ov.add_argument("text")
+    ov.add_argument("pattern")
 
     args = parser.parse_args()
     if args.cmd == "char":
@@ -45,6 +48,14 @@ def main():
                 count = count + 1
             i = i + 1
         print(count)
+    elif args.cmd == "overlap":
+        count = 0
+        i = 0
+        while i + len(args.pattern) <= len(args.text):
+            if args.text[i : i + len(args.pattern)] == args.pattern:
+                count = count + 1
+            i = i + 1
+        print(count)
 
 
 if __name__ == "__main__":
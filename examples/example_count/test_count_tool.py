@@ -0,0 +1,82 @@
+"""Tests for count_tool.py"""
+
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "count_tool.py"
+
+GOLDEN = GoldenCli(SCRIPT)
+run = GOLDEN.run
+
+
+class TestAscii:
+    def test_char(self):
+        stdout, _, code = run(["char", "banana", "a"])
+        assert code == 0
+        assert stdout.strip() == "3"
+
+    def test_vowels(self):
+        stdout, _, _ = run(["vowels", "education"])
+        assert stdout.strip() == "5"
+
+    def test_consonants(self):
+        stdout, _, _ = run(["consonants", "rhythm"])
+        assert stdout.strip() == "6"
+
+    def test_consonants_lowercase_only(self):
+        stdout, _, _ = run(["consonants", "HeLLo"])
+        assert stdout.strip() == "0"
+
+
+class TestOverlap:
+    def test_overlapping(self):
+        stdout, _, code = run(["overlap", "aaaa", "aa"])
+        assert code == 0
+        assert stdout.strip() == "3"
+
+    def test_differs_from_str_count(self):
+        assert "aaaa".count("aa") == 2
+        stdout, _, _ = run(["overlap", "abababa", "aba"])
+        assert stdout.strip() == "3"
+
+    def test_pattern_longer_than_text(self):
+        stdout, _, _ = run(["overlap", "a", "aa"])
+        assert stdout.strip() == "0"
+
+    def test_multibyte(self):
+        stdout, _, _ = run(["overlap", "ééé", "éé"])
+        assert stdout.strip() == "2"
+
+
+class TestMultibyte:
+    """s[i] and len(s) count characters, not UTF-8 bytes"""
+
+    def test_vowels_in_cafe(self):
+        stdout, _, code = run(["vowels", "café"])
+        assert code == 0
+        assert stdout.strip() == "1"
+
+    def test_char_multibyte_target(self):
+        stdout, _, _ = run(["char", "été", "é"])
+        assert stdout.strip() == "2"
+
+    def test_consonants_skip_non_ascii(self):
+        stdout, _, _ = run(["consonants", "naïve"])
+        assert stdout.strip() == "2"
+
+
+class TestGolden:
+    @pytest.mark.parametrize(
+        "argv,stdout",
+        [
+            (["vowels", "café"], "1\n"),
+            (["char", "été", "é"], "2\n"),
+            (["consonants", "HeLLo wz"], "2\n"),
+            (["overlap", "aaaa", "aa"], "3\n"),
+        ],
+    )
+    def test_golden(self, argv, stdout):
+        GOLDEN.check(argv, stdout)
@@ -0,0 +1,73 @@
+"""Tests for csv_basic_cli.py"""
+
+import subprocess
+from pathlib import Path
+
+from csv_basic_cli import (
+    parse_csv,
+    parse_csv_dict,
+    read_csv_dict_file,
+    read_csv_file,
+    to_csv,
+    write_csv_dict_file,
+    write_csv_file,
+)
+
+SCRIPT = Path(__file__).parent / "csv_basic_cli.py"
+
+ROWS = [
+    ["name", "city", "note"],
+    ["Ada", "London", "plain"],
+    ["Grace", "New York, NY", 'says "hi"'],
+    ["Linus", "Helsinki", "two\nlines"],
+]
+
+
+def run_cli(*args):
+    """Helper to run CLI and capture output"""
+    return subprocess.run(["python3", str(SCRIPT), *args], capture_output=True, text=True)
+
+
+class TestRoundTrip:
+    def test_string_round_trip(self):
+        assert parse_csv(to_csv(ROWS)) == ROWS
+
+    def test_quoting(self):
+        text = to_csv(ROWS)
+        assert '"New York, NY"' in text
+        assert '"says ""hi"""' in text
+
+    def test_file_round_trip(self, tmp_path):
+        p = tmp_path / "people.csv"
+        write_csv_file(str(p), ROWS)
+        assert read_csv_file(str(p)) == ROWS
+
+    def test_delimiter(self):
+        assert parse_csv(to_csv(ROWS, delimiter=";"), delimiter=";") == ROWS
+
+
+class TestDictReader:
+    def test_rows_as_dicts(self):
+        data = parse_csv_dict("name,age\nAda,36\nAlan,41\n")
+        assert data == [{"name": "Ada", "age": "36"}, {"name": "Alan", "age": "41"}]
+
+    def test_dict_file_round_trip(self, tmp_path):
+        p = tmp_path / "ages.csv"
+        data = [{"name": "Ada", "age": "36"}, {"name": "Alan", "age": "41"}]
+        write_csv_dict_file(str(p), data)
+        assert read_csv_dict_file(str(p)) == data
+
+
+class TestCli:
+    def test_count(self, tmp_path):
+        p = tmp_path / "people.csv"
+        write_csv_file(str(p), ROWS)
+        result = run_cli("count", str(p))
+        assert result.returncode == 0
+        assert result.stdout == "Rows: 4\nColumns: 3\n"
+
+    def test_column(self, tmp_path):
+        p = tmp_path / "people.csv"
+        write_csv_file(str(p), ROWS)
+        result = run_cli("column", str(p), "1")
+        assert result.stdout == "city\nLondon\nNew York, NY\nHelsinki\n"
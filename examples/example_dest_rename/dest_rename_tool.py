@@ -0,0 +1,59 @@
+#!/usr/bin/env python3
+"""Dest Rename Example - argparse dest= renaming CLI.
+
+`dest=` replaces the attribute name argparse derives from the flag, so
+`--input-file` is read back as `args.infile`, not `args.input_file`.
+
+Examples:
+    >>> args = build_parser().parse_args(["count", "--input-file", "a.txt"])
+    >>> args.infile
+    'a.txt'
+    >>> hasattr(args, "input_file")
+    False
+"""
+
+import argparse
+import sys
+
+
+def build_parser() -> argparse.ArgumentParser:
+    """Parser whose options are stored under renamed attributes.
+
+    >>> args = build_parser().parse_args(["copy", "--input-file", "a", "-o", "b", "-n"])
+    >>> args.infile, args.outfile, args.dry
+    ('a', 'b', True)
+    """
+    parser = argparse.ArgumentParser(description="dest= renaming tool")
+    subs = parser.add_subparsers(dest="cmd", required=True)
+
+    c = subs.add_parser("count")
+    c.add_argument("--input-file", dest="infile", required=True)
+    cp = subs.add_parser("copy")
+    cp.add_argument("--input-file", dest="infile", required=True)
+    cp.add_argument("-o", "--output-file", dest="outfile", required=True)
+    cp.add_argument("-n", "--dry-run", dest="dry", action="store_true")
+    return parser
+
+
+def main():
+    args = build_parser().parse_args()
+    try:
+        with open(args.infile) as f:
+            text = f.read()
+    except OSError as e:
+        print(f"Error: {e}", file=sys.stderr)
+        sys.exit(1)
+
+    if args.cmd == "count":
+        print(len(text.splitlines()))
+    elif args.cmd == "copy":
+        if args.dry:
+            print(f"would copy {args.infile} -> {args.outfile}")
+        else:
+            with open(args.outfile, "w") as f:
+                f.write(text)
+            print(f"copied {args.infile} -> {args.outfile}")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,75 @@
+"""Tests for dest_rename_tool.py"""
+
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "dest_rename_tool.py"
+
+GOLDEN = GoldenCli(SCRIPT)
+run = GOLDEN.run
+
+
+class TestCount:
+    def test_input_file(self, tmp_path):
+        src = tmp_path / "in.txt"
+        src.write_text("a\nb\nc\n")
+        stdout, _, code = run(["count", "--input-file", str(src)])
+        assert code == 0
+        assert stdout.strip() == "3"
+
+    def test_missing_file(self, tmp_path):
+        _, stderr, code = run(["count", "--input-file", str(tmp_path / "nope")])
+        assert code == 1
+        assert "Error:" in stderr
+
+    def test_required(self):
+        _, stderr, code = run(["count"])
+        assert code == 2
+        assert "--input-file" in stderr
+
+
+class TestCopy:
+    def test_copy(self, tmp_path):
+        src = tmp_path / "in.txt"
+        dst = tmp_path / "out.txt"
+        src.write_text("hello\n")
+        stdout, _, code = run(["copy", "--input-file", str(src), "--output-file", str(dst)])
+        assert code == 0
+        assert stdout.strip() == f"copied {src} -> {dst}"
+        assert dst.read_text() == "hello\n"
+
+    def test_dry_run_short_flags(self, tmp_path):
+        src = tmp_path / "in.txt"
+        dst = tmp_path / "out.txt"
+        src.write_text("hello\n")
+        stdout, _, _ = run(["copy", "--input-file", str(src), "-o", str(dst), "-n"])
+        assert stdout.strip() == f"would copy {src} -> {dst}"
+        assert not dst.exists()
+
+
+class TestHelp:
+    def test_help_shows_flag_not_dest(self):
+        stdout, _, code = run(["copy", "--help"])
+        assert code == 0
+        assert "--input-file INFILE" in stdout
+        assert "--output-file OUTFILE" in stdout
+
+
+class TestGolden:
+    """Fields must be named after dest=, not after the flag."""
+
+    @pytest.mark.parametrize(
+        "argv,stdout",
+        [
+            (["count", "--input-file", "/dev/null"], "0\n"),
+            (
+                ["copy", "--input-file", "/dev/null", "-o", "out.txt", "--dry-run"],
+                "would copy /dev/null -> out.txt\n",
+            ),
+        ],
+    )
+    def test_golden(self, argv, stdout):
+        GOLDEN.check(argv, stdout)
@@ -0,0 +1,78 @@
+#!/usr/bin/env python3
+"""Dict Lookup Example - Fallible d[k] lookups with KeyError handling CLI.
+
+`d[k]` raises KeyError for a missing key. Each lookup here either lets
+that propagate to main, which reports it and exits 1, or converts it
+into a clearer error for the caller.
+
+Examples:
+    >>> PORTS["http"]
+    80
+    >>> port_for("ssh")
+    22
+"""
+
+import argparse
+import sys
+
+PORTS = {"ssh": 22, "http": 80, "https": 443, "postgres": 5432}
+
+
+def port_for(service: str) -> int:
+    """Port for a known service.
+
+    >>> port_for("gopher")
+    Traceback (most recent call last):
+        ...
+    KeyError: 'gopher'
+    """
+    port = PORTS[service]
+    return port
+
+
+def lookup_all(services: list[str]) -> list[int]:
+    """Ports for several services, naming the first unknown one.
+
+    >>> lookup_all(["ssh", "https"])
+    [22, 443]
+    >>> lookup_all(["ssh", "nope"])
+    Traceback (most recent call last):
+        ...
+    ValueError: unknown service: nope
+    """
+    ports = []
+    for name in services:
+        try:
+            port = PORTS[name]
+        except KeyError:
+            raise ValueError(f"unknown service: {name}") from None
+        ports.append(port)
+    return ports
+
+
+def main():
+    parser = argparse.ArgumentParser(description="Service port lookup tool")
+    subs = parser.add_subparsers(dest="cmd", required=True)
+
+    p = subs.add_parser("port")
+    p.add_argument("service")
+    a = subs.add_parser("all")
+    a.add_argument("services", nargs="+")
+
+    args = parser.parse_args()
+    if args.cmd == "port":
+        try:
+            print(port_for(args.service))
+        except KeyError as e:
+            print(f"Error: missing key {e}", file=sys.stderr)
+            sys.exit(1)
+    elif args.cmd == "all":
+        try:
+            print(" ".join(str(p) for p in lookup_all(args.services)))
+        except ValueError as e:
+            print(f"Error: {e}", file=sys.stderr)
+            sys.exit(1)
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,34 @@
+"""Tests for dict_lookup_tool.py"""
+
+from pathlib import Path
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "dict_lookup_tool.py"
+
+run = GoldenCli(SCRIPT).run
+
+
+class TestPort:
+    def test_known(self):
+        stdout, _, code = run(["port", "https"])
+        assert code == 0
+        assert stdout.strip() == "443"
+
+    def test_missing_key(self):
+        stdout, stderr, code = run(["port", "gopher"])
+        assert code == 1
+        assert stdout == ""
+        assert stderr.strip() == "Error: missing key 'gopher'"
+
+
+class TestAll:
+    def test_known(self):
+        stdout, _, _ = run(["all", "ssh", "http"])
+        assert stdout.strip() == "22 80"
+
+    def test_first_missing_reported(self):
+        stdout, stderr, code = run(["all", "ssh", "nope", "gone"])
+        assert code == 1
+        assert stdout == ""
+        assert stderr.strip() == "Error: unknown service: nope"
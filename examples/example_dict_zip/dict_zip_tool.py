@@ -0,0 +1,91 @@
+#!/usr/bin/env python3
+"""Dict Zip Example - Building dicts from parallel lists and pairs CLI.
+
+Keys are printed in sorted order so the output does not depend on the
+iteration order of the underlying map.
+
+Examples:
+    >>> from_lists(["a", "b"], ["1", "2"])
+    {'a': '1', 'b': '2'}
+    >>> from_pairs(["x=1", "y=2"])
+    {'x': '1', 'y': '2'}
+"""
+
+import argparse
+import sys
+
+
+def from_lists(keys: list[str], values: list[str]) -> dict[str, str]:
+    """Pair keys with values; zip stops at the shorter list.
+
+    >>> from_lists(["a", "b", "c"], ["1", "2"])
+    {'a': '1', 'b': '2'}
+    >>> from_lists(["k", "k"], ["old", "new"])
+    {'k': 'new'}
+    """
+    return dict(zip(keys, values))
+
+
+def from_pairs(items: list[str]) -> dict[str, str]:
+    """Build a dict from key=value strings.
+
+    >>> from_pairs(["host=db", "port=5432"])
+    {'host': 'db', 'port': '5432'}
+    >>> from_pairs(["url=a=b"])
+    {'url': 'a=b'}
+    >>> from_pairs(["broken"])
+    Traceback (most recent call last):
+        ...
+    ValueError: expected key=value, got 'broken'
+    """
+    pairs = []
+    for item in items:
+        if "=" not in item:
+            raise ValueError(f"expected key=value, got '{item}'")
+        key, value = item.split("=", 1)
+        pairs.append((key, value))
+    return dict(pairs)
+
+
+def render(d: dict[str, str]) -> str:
+    """One key=value line per entry, sorted by key.
+
+    >>> render({"b": "2", "a": "1"})
+    'a=1\\nb=2'
+    """
+    return "\n".join(f"{k}={v}" for k, v in sorted(d.items()))
+
+
+def main():
+    parser = argparse.ArgumentParser(description="Dict construction tool")
+    subs = parser.add_subparsers(dest="cmd", required=True)
+
+    z = subs.add_parser("zip")
+    z.add_argument("--keys", nargs="+", required=True)
+    z.add_argument("--values", nargs="+", required=True)
+    p = subs.add_parser("pairs")
+    p.add_argument("items", nargs="+")
+    g = subs.add_parser("get")
+    g.add_argument("key")
+    g.add_argument("--keys", nargs="+", required=True)
+    g.add_argument("--values", nargs="+", required=True)
+
+    args = parser.parse_args()
+    try:
+        if args.cmd == "zip":
+            print(render(from_lists(args.keys, args.values)))
+        elif args.cmd == "pairs":
+            print(render(from_pairs(args.items)))
+        elif args.cmd == "get":
+            d = from_lists(args.keys, args.values)
+            print(d[args.key])
+    except ValueError as e:
+        print(f"Error: {e}", file=sys.stderr)
+        sys.exit(1)
+    except KeyError as e:
+        print(f"Error: missing key {e}", file=sys.stderr)
+        sys.exit(1)
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,70 @@
+"""Tests for dict_zip_tool.py"""
+
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "dict_zip_tool.py"
+
+GOLDEN = GoldenCli(SCRIPT)
+run = GOLDEN.run
+
+
+class TestZip:
+    def test_two_lists(self):
+        stdout, _, code = run(["zip", "--keys", "b", "a", "c", "--values", "2", "1", "3"])
+        assert code == 0
+        assert stdout == "a=1\nb=2\nc=3\n"
+
+    def test_shorter_values(self):
+        stdout, _, _ = run(["zip", "--keys", "a", "b", "c", "--values", "1"])
+        assert stdout == "a=1\n"
+
+    def test_duplicate_key_last_wins(self):
+        stdout, _, _ = run(["zip", "--keys", "k", "k", "--values", "old", "new"])
+        assert stdout == "k=new\n"
+
+
+class TestPairs:
+    def test_pairs(self):
+        stdout, _, code = run(["pairs", "port=5432", "host=db"])
+        assert code == 0
+        assert stdout == "host=db\nport=5432\n"
+
+    def test_value_with_equals(self):
+        stdout, _, _ = run(["pairs", "url=a=b"])
+        assert stdout == "url=a=b\n"
+
+    def test_malformed(self):
+        _, stderr, code = run(["pairs", "broken"])
+        assert code == 1
+        assert "Error: expected key=value, got 'broken'" in stderr
+
+
+class TestGet:
+    def test_found(self):
+        stdout, _, _ = run(["get", "b", "--keys", "a", "b", "--values", "1", "2"])
+        assert stdout == "2\n"
+
+    def test_missing(self):
+        _, stderr, code = run(["get", "z", "--keys", "a", "--values", "1"])
+        assert code == 1
+        assert "Error: missing key 'z'" in stderr
+
+
+class TestGolden:
+    """dict(zip(...)) and dict(pairs) must collect into a map."""
+
+    @pytest.mark.parametrize(
+        "argv,stdout",
+        [
+            (["zip", "--keys", "b", "a", "--values", "2", "1"], "a=1\nb=2\n"),
+            (["zip", "--keys", "a", "b", "c", "--values", "1"], "a=1\n"),
+            (["pairs", "y=2", "x=1"], "x=1\ny=2\n"),
+            (["get", "b", "--keys", "a", "b", "--values", "1", "2"], "2\n"),
+        ],
+    )
+    def test_golden(self, argv, stdout):
+        GOLDEN.check(argv, stdout)
@@ -11,6 +11,22 @@ Examples:
 """
 
 import argparse
+import sys
+
+
+def check_divisor(b: int) -> None:
+    """Raise ZeroDivisionError for a zero divisor.
+
+    The message is fixed here rather than left to the interpreter, whose
+    wording differs between // and % and between Python versions.
+
+    >>> check_divisor(0)
+    Traceback (most recent call last):
+        ...
+    ZeroDivisionError: division by zero
+    """
+    if b == 0:
+        raise ZeroDivisionError("division by zero")
 
 
 def integer_divide(a: int, b: int) -> int:
@@ -23,6 +39,7 @@ def integer_divide(a: int, b: int) -> int:
     >>> integer_divide(-10, 3)
     -4
     """
+    check_divisor(b)
     return a // b
 
 
@@ -35,7 +52,10 @@ def modulo(a: int, b: int) -> int:
     0
     >>> modulo(7, 4)
     3
+    >>> modulo(-7, 2)
+    1
     """
+    check_divisor(b)
     return a % b
 
 
@@ -47,9 +67,23 @@ def divmod_pair(a: int, b: int) -> tuple:
     >>> divmod_pair(20, 7)
     (2, 6)
     """
+    check_divisor(b)
     return (a // b, a % b)
 
 
+def to_hms(seconds: int) -> str:
+    """Format a duration as H:MM:SS by unpacking two divmod pairs.
+
+    >>> to_hms(3661)
+    '1:01:01'
+    >>> to_hms(59)
+    '0:00:59'
+    """
+    minutes, secs = divmod_pair(seconds, 60)
+    hours, minutes = divmod_pair(minutes, 60)
+    return f"{hours}:{minutes:02d}:{secs:02d}"
+
+
 def main():
     parser = argparse.ArgumentParser(description="Divmod tool")
     subs = parser.add_subparsers(dest="cmd", required=True)
@@ -63,15 +97,23 @@ def main():
     r = subs.add_parser("rem")
     r.add_argument("a", type=int)
     r.add_argument("b", type=int)
+    h = subs.add_parser("hms")
+    h.add_argument("seconds", type=int)
 
     args = parser.parse_args()
-    if args.cmd == "calc":
-        q, r = divmod_pair(args.a, args.b)
-        print(f"{q} {r}")
-    elif args.cmd == "quot":
-        print(integer_divide(args.a, args.b))
-    elif args.cmd == "rem":
-        print(modulo(args.a, args.b))
+    try:
+        if args.cmd == "calc":
+            q, r = divmod_pair(args.a, args.b)
+            print(f"{q} {r}")
+        elif args.cmd == "quot":
+            print(integer_divide(args.a, args.b))
+        elif args.cmd == "rem":
+            print(modulo(args.a, args.b))
+        elif args.cmd == "hms":
+            print(to_hms(args.seconds))
+    except ZeroDivisionError as e:
+        print(f"Error: {e}", file=sys.stderr)
+        sys.exit(1)
 
 
 if __name__ == "__main__":
@@ -0,0 +1,138 @@
+"""Tests for divmod_tool.py"""
+
+import re
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "divmod_tool.py"
+
+GOLDEN = GoldenCli(SCRIPT)
+run = GOLDEN.run
+
+# The same source with main returning anyhow::Result
+ANYHOW = GoldenCli(SCRIPT, errors="anyhow")
+
+
+class TestCalc:
+    def test_calc(self):
+        stdout, _, code = run(["calc", "7", "3"])
+        assert code == 0
+        assert stdout.strip() == "2 1"
+
+    def test_calc_negative_floors(self):
+        stdout, _, _ = run(["calc", "-7", "3"])
+        assert stdout.strip() == "-3 2"
+
+
+class TestQuotRem:
+    def test_quot(self):
+        stdout, _, _ = run(["quot", "17", "5"])
+        assert stdout.strip() == "3"
+
+    def test_rem(self):
+        stdout, _, _ = run(["rem", "17", "5"])
+        assert stdout.strip() == "2"
+
+
+class TestFloorSemantics:
+    """Quotients round toward negative infinity; remainders take the divisor's sign."""
+
+    @pytest.mark.parametrize(
+        "a,b,q,r",
+        [
+            ("-7", "2", "-4", "1"),
+            ("7", "-2", "-4", "-1"),
+            ("-7", "-2", "3", "-1"),
+            ("-6", "2", "-3", "0"),
+            ("0", "-3", "0", "0"),
+        ],
+    )
+    def test_signs(self, a, b, q, r):
+        stdout, _, _ = run(["quot", a, b])
+        assert stdout.strip() == q
+        stdout, _, _ = run(["rem", a, b])
+        assert stdout.strip() == r
+        stdout, _, _ = run(["calc", a, b])
+        assert stdout.strip() == f"{q} {r}"
+
+
+class TestTupleUnpacking:
+    """divmod_pair returns a 2-tuple that callers unpack with `a, b = f()`."""
+
+    @pytest.mark.parametrize(
+        "seconds,expected",
+        [
+            ("3661", "1:01:01"),
+            ("59", "0:00:59"),
+            ("0", "0:00:00"),
+            ("86399", "23:59:59"),
+            ("90000", "25:00:00"),
+        ],
+    )
+    def test_hms(self, seconds, expected):
+        stdout, _, code = run(["hms", seconds])
+        assert code == 0
+        assert stdout.strip() == expected
+
+
+class TestZeroDivisor:
+    @pytest.mark.parametrize("cmd", ["calc", "quot", "rem"])
+    def test_zero(self, cmd):
+        stdout, stderr, code = run([cmd, "5", "0"])
+        assert code == 1
+        assert stdout == ""
+        assert stderr.strip() == "Error: division by zero"
+
+    def test_zero_dividend_is_fine(self):
+        stdout, _, code = run(["quot", "0", "5"])
+        assert code == 0
+        assert stdout.strip() == "0"
+
+
+class TestGolden:
+    """Every subcommand arm must bind the fields its body reads"""
+
+    @pytest.mark.parametrize(
+        "argv,stdout",
+        [
+            (["calc", "7", "3"], "2 1\n"),
+            (["quot", "7", "3"], "2\n"),
+            (["rem", "7", "3"], "1\n"),
+            (["quot", "-7", "2"], "-4\n"),
+            (["quot", "7", "-2"], "-4\n"),
+            (["quot", "-7", "-2"], "3\n"),
+            (["rem", "-7", "2"], "1\n"),
+            (["rem", "7", "-2"], "-1\n"),
+            (["calc", "-6", "2"], "-3 0\n"),
+            (["hms", "3661"], "1:01:01\n"),
+            (["hms", "90000"], "25:00:00\n"),
+        ],
+    )
+    def test_golden(self, argv, stdout):
+        GOLDEN.check(argv, stdout)
+
+    @pytest.mark.parametrize("cmd", ["calc", "quot", "rem"])
+    def test_golden_zero_divisor(self, cmd):
+        GOLDEN.check([cmd, "5", "0"], stdout="", returncode=1)
+
+
+class TestAnyhow:
+    """With --errors=anyhow, ZeroDivisionError propagates through main with context."""
+
+    def test_main_returns_anyhow_result(self):
+        source = ANYHOW.rust_source_or_skip()
+        assert re.search(r"fn main\(\)\s*->\s*anyhow::Result<\(\)>", source)
+        assert ".context(" in source or ".with_context(" in source
+
+    def test_error_carries_message(self):
+        if not ANYHOW.has_rust:
+            pytest.skip(ANYHOW.skip_reason)
+        result = ANYHOW.run_rust(["quot", "5", "0"])
+        assert result.returncode != 0
+        assert "division by zero" in result.stderr
+
+    def test_golden(self):
+        ANYHOW.check(["quot", "-7", "2"], stdout="-4\n")
@@ -0,0 +1,80 @@
+#!/usr/bin/env python3
+"""Encoding Example - str.encode/bytes.decode with explicit codecs CLI.
+
+Bytes are shown and read as space-separated hex. Characters outside
+the codec (e.g. "é" in ascii) and invalid byte sequences (e.g. a lone
+0xff in utf-8) raise UnicodeError; latin-1 maps every byte, so decoding
+with it never fails.
+
+Examples:
+    >>> to_hex("hé", "utf-8")
+    '68 c3 a9'
+    >>> to_hex("hé", "latin-1")
+    '68 e9'
+    >>> from_hex("68 c3 a9", "utf-8")
+    'hé'
+"""
+
+import argparse
+import sys
+
+CODECS = ["utf-8", "ascii", "latin-1"]
+
+
+def to_hex(text: str, codec: str) -> str:
+    """Encode text and show the bytes as hex.
+
+    >>> to_hex("", "ascii")
+    ''
+    >>> to_hex("é", "ascii")
+    Traceback (most recent call last):
+        ...
+    UnicodeEncodeError: 'ascii' codec can't encode character '\\xe9' in position 0: ordinal not in range(128)
+    """
+    return " ".join(f"{b:02x}" for b in text.encode(codec))
+
+
+def from_hex(hex_text: str, codec: str) -> str:
+    """Decode hex bytes to text.
+
+    >>> from_hex("ff", "latin-1")
+    'ÿ'
+    >>> from_hex("ff", "utf-8")
+    Traceback (most recent call last):
+        ...
+    UnicodeDecodeError: 'utf-8' codec can't decode byte 0xff in position 0: invalid start byte
+    """
+    return bytes.fromhex(hex_text).decode(codec)
+
+
+def main():
+    parser = argparse.ArgumentParser(description="Text encoding tool")
+    subs = parser.add_subparsers(dest="cmd", required=True)
+
+    e = subs.add_parser("encode")
+    e.add_argument("text")
+    e.add_argument("--codec", choices=CODECS, default="utf-8")
+    d = subs.add_parser("decode")
+    d.add_argument("hex")
+    d.add_argument("--codec", choices=CODECS, default="utf-8")
+    r = subs.add_parser("roundtrip")
+    r.add_argument("text")
+    r.add_argument("--codec", choices=CODECS, default="utf-8")
+
+    args = parser.parse_args()
+    try:
+        if args.cmd == "encode":
+            print(to_hex(args.text, args.codec))
+        elif args.cmd == "decode":
+            print(from_hex(args.hex, args.codec))
+        elif args.cmd == "roundtrip":
+            data = args.text.encode(args.codec)
+            back = data.decode(args.codec)
+            print(f"{len(data)} bytes, same={back == args.text}")
+    except ValueError as e:
+        print(f"Error: {e}", file=sys.stderr)
+        sys.exit(1)
+
+
+if __name__ == "__main__":
+    main()
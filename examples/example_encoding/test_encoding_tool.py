@@ -0,0 +1,107 @@
+"""Tests for encoding_tool.py"""
+
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "encoding_tool.py"
+
+GOLDEN = GoldenCli(SCRIPT)
+run = GOLDEN.run
+
+
+class TestEncode:
+    def test_utf8(self):
+        stdout, _, code = run(["encode", "hé€"])
+        assert code == 0
+        assert stdout.strip() == "68 c3 a9 e2 82 ac"
+
+    def test_latin1(self):
+        stdout, _, _ = run(["encode", "hé", "--codec", "latin-1"])
+        assert stdout.strip() == "68 e9"
+
+    def test_ascii(self):
+        stdout, _, _ = run(["encode", "hi", "--codec", "ascii"])
+        assert stdout.strip() == "68 69"
+
+    def test_invalid_ascii(self):
+        stdout, stderr, code = run(["encode", "hé", "--codec", "ascii"])
+        assert code == 1
+        assert stdout == ""
+        assert "Error: 'ascii' codec can't encode character" in stderr
+
+    def test_euro_not_in_latin1(self):
+        _, stderr, code = run(["encode", "€", "--codec", "latin-1"])
+        assert code == 1
+        assert "'latin-1' codec can't encode" in stderr
+
+
+class TestDecode:
+    def test_utf8(self):
+        stdout, _, _ = run(["decode", "68 c3 a9"])
+        assert stdout.strip() == "hé"
+
+    def test_latin1_any_byte(self):
+        stdout, _, code = run(["decode", "ff", "--codec", "latin-1"])
+        assert code == 0
+        assert stdout.strip() == "ÿ"
+
+    def test_invalid_utf8(self):
+        _, stderr, code = run(["decode", "ff"])
+        assert code == 1
+        assert "Error: 'utf-8' codec can't decode byte 0xff" in stderr
+
+    def test_invalid_ascii(self):
+        _, stderr, code = run(["decode", "68 e9", "--codec", "ascii"])
+        assert code == 1
+        assert "'ascii' codec can't decode byte 0xe9" in stderr
+
+    def test_bad_hex(self):
+        _, stderr, code = run(["decode", "zz"])
+        assert code == 1
+        assert "Error:" in stderr
+
+
+class TestRoundTrip:
+    @pytest.mark.parametrize(
+        "text,codec,expected",
+        [
+            ("hé€", "utf-8", "6 bytes, same=True"),
+            ("hé", "latin-1", "2 bytes, same=True"),
+            ("plain", "ascii", "5 bytes, same=True"),
+        ],
+    )
+    def test_roundtrip(self, text, codec, expected):
+        stdout, _, code = run(["roundtrip", text, "--codec", codec])
+        assert code == 0
+        assert stdout.strip() == expected
+
+
+class TestGolden:
+    """encode/decode must honour the codec and fail where Python raises."""
+
+    @pytest.mark.parametrize(
+        "argv,stdout",
+        [
+            (["encode", "hé€"], "68 c3 a9 e2 82 ac\n"),
+            (["encode", "hé", "--codec", "latin-1"], "68 e9\n"),
+            (["decode", "68 c3 a9"], "hé\n"),
+            (["decode", "ff", "--codec", "latin-1"], "ÿ\n"),
+            (["roundtrip", "hé€"], "6 bytes, same=True\n"),
+        ],
+    )
+    def test_golden(self, argv, stdout):
+        GOLDEN.check(argv, stdout)
+
+    @pytest.mark.parametrize(
+        "argv",
+        [
+            ["encode", "hé", "--codec", "ascii"],
+            ["decode", "ff"],
+            ["decode", "68 e9", "--codec", "ascii"],
+        ],
+    )
+    def test_golden_errors(self, argv):
+        GOLDEN.check(argv, stdout="", returncode=1)
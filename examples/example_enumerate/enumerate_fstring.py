@@ -0,0 +1,42 @@
+#!/usr/bin/env python3
+"""Enumerate F-String Example - enumerate_tool.py written idiomatically.
+
+Same CLI and output as enumerate_tool.py, but built from `enumerate`
+and f-strings instead of manual index counters and concatenation.
+"""
+
+import argparse
+
+
+def main():
+    parser = argparse.ArgumentParser(description="Enumerate operations tool")
+    subs = parser.add_subparsers(dest="cmd", required=True)
+
+    i = subs.add_parser("index")
+    i.add_argument("text")
+    s = subs.add_parser("start")
+    s.add_argument("text")
+    s.add_argument("offset", type=int)
+    r = subs.add_parser("reverse")
+    r.add_argument("text")
+
+    args = parser.parse_args()
+    if args.cmd == "index":
+        parts = []
+        for idx, c in enumerate(args.text):
+            parts.append(f"{idx}:{c}")
+        print(" ".join(parts))
+    elif args.cmd == "start":
+        parts = []
+        for idx, c in enumerate(args.text, start=args.offset):
+            parts.append(f"{idx}:{c}")
+        print(" ".join(parts))
+    elif args.cmd == "reverse":
+        parts = []
+        for idx, c in reversed(list(enumerate(args.text))):
+            parts.append(f"{idx}:{c}")
+        print(" ".join(parts))
+
+
+if __name__ == "__main__":
+    main()
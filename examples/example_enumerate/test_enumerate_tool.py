@@ -0,0 +1,67 @@
+"""Tests for enumerate_tool.py and its f-string twin enumerate_fstring.py"""
+
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+HERE = Path(__file__).parent
+SCRIPT = HERE / "enumerate_tool.py"
+FSTRING_SCRIPT = HERE / "enumerate_fstring.py"
+
+GOLDEN = GoldenCli(SCRIPT)
+FSTRING = GoldenCli(FSTRING_SCRIPT)
+run = GOLDEN.run
+
+
+class TestEnumerate:
+    def test_index(self):
+        stdout, _, code = run(["index", "abc"])
+        assert code == 0
+        assert stdout.strip() == "0:a 1:b 2:c"
+
+    def test_start(self):
+        stdout, _, code = run(["start", "abc", "10"])
+        assert code == 0
+        assert stdout.strip() == "10:a 11:b 12:c"
+
+    def test_start_negative(self):
+        stdout, _, _ = run(["start", "ab", "-1"])
+        assert stdout.strip() == "-1:a 0:b"
+
+    def test_reverse(self):
+        stdout, _, _ = run(["reverse", "abc"])
+        assert stdout.strip() == "2:c 1:b 0:a"
+
+
+@pytest.mark.parametrize(
+    "args",
+    [
+        ["index", "abc"],
+        ["index", "x"],
+        ["index", "héllo"],
+        ["start", "abc", "10"],
+        ["start", "ab", "-1"],
+        ["reverse", "abcd"],
+    ],
+)
+def test_fstring_matches_manual(args):
+    expected = run(args)
+    actual = FSTRING.run(args)
+    assert actual == expected
+
+
+class TestGolden:
+    """result starts as "" and grows by +; it must compile as an owned String"""
+
+    @pytest.mark.parametrize(
+        "argv,stdout",
+        [
+            (["index", "abc"], "0:a 1:b 2:c\n"),
+            (["start", "abc", "10"], "10:a 11:b 12:c\n"),
+            (["reverse", "abc"], "2:c 1:b 0:a\n"),
+        ],
+    )
+    def test_golden(self, argv, stdout):
+        GOLDEN.check(argv, stdout)
@@ -0,0 +1,73 @@
+#!/usr/bin/env python3
+"""Enumerate In-Place Example - Mutating a list while enumerating it CLI.
+
+`for i, x in enumerate(xs): xs[i] = f(x)` reads each element and writes
+back through its index in the same loop.
+
+Examples:
+    >>> double_all([1, 2, 3])
+    [2, 4, 6]
+    >>> clamp_all([1, 9, 5], 6)
+    [1, 6, 5]
+"""
+
+import argparse
+
+
+def double_all(xs: list[int]) -> list[int]:
+    """Double every element in place.
+
+    >>> double_all([])
+    []
+    """
+    for i, x in enumerate(xs):
+        xs[i] = x * 2
+    return xs
+
+
+def clamp_all(xs: list[int], limit: int) -> list[int]:
+    """Cap every element at limit, in place.
+
+    >>> clamp_all([-3, 3], 0)
+    [-3, 0]
+    """
+    for i, x in enumerate(xs):
+        if x > limit:
+            xs[i] = limit
+    return xs
+
+
+def upper_all(words: list[str]) -> list[str]:
+    """Uppercase every word in place.
+
+    >>> upper_all(["ab", "Cd"])
+    ['AB', 'CD']
+    """
+    for i, w in enumerate(words):
+        words[i] = w.upper()
+    return words
+
+
+def main():
+    parser = argparse.ArgumentParser(description="In-place enumerate tool")
+    subs = parser.add_subparsers(dest="cmd", required=True)
+
+    d = subs.add_parser("double")
+    d.add_argument("values", type=int, nargs="+")
+    c = subs.add_parser("clamp")
+    c.add_argument("limit", type=int)
+    c.add_argument("values", type=int, nargs="+")
+    u = subs.add_parser("upper")
+    u.add_argument("words", nargs="+")
+
+    args = parser.parse_args()
+    if args.cmd == "double":
+        print(" ".join(str(x) for x in double_all(args.values)))
+    elif args.cmd == "clamp":
+        print(" ".join(str(x) for x in clamp_all(args.values, args.limit)))
+    elif args.cmd == "upper":
+        print(" ".join(upper_all(args.words)))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,28 @@
+"""Tests for enumerate_inplace_tool.py"""
+
+from pathlib import Path
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "enumerate_inplace_tool.py"
+
+run = GoldenCli(SCRIPT).run
+
+
+class TestDouble:
+    def test_double_in_place(self):
+        stdout, _, code = run(["double", "1", "2", "3", "-4"])
+        assert code == 0
+        assert stdout.strip() == "2 4 6 -8"
+
+
+class TestClamp:
+    def test_clamp(self):
+        stdout, _, _ = run(["clamp", "5", "1", "7", "5", "10"])
+        assert stdout.strip() == "1 5 5 5"
+
+
+class TestUpper:
+    def test_upper(self):
+        stdout, _, _ = run(["upper", "foo", "Bar"])
+        assert stdout.strip() == "FOO BAR"
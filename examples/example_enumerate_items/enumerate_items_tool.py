@@ -0,0 +1,79 @@
+#!/usr/bin/env python3
+"""Enumerate Items Example - Numbering dict entries CLI.
+
+`for i, (k, v) in enumerate(d.items())` nests a `(key, value)` tuple
+target inside the `(index, item)` pair. Dicts keep insertion order, so
+the numbering follows the order the pairs were given.
+
+Examples:
+    >>> numbered({"b": 2, "a": 1})
+    ['1. b=2', '2. a=1']
+    >>> numbered({"x": 9}, start=0)
+    ['0. x=9']
+"""
+
+import argparse
+import sys
+
+
+def parse_pairs(pairs: list[str]) -> dict[str, str]:
+    """Parse KEY=VALUE pairs, later keys overwriting earlier ones in place.
+
+    >>> parse_pairs(["a=1", "b=2", "a=3"])
+    {'a': '3', 'b': '2'}
+    """
+    d = {}
+    for pair in pairs:
+        key, sep, value = pair.partition("=")
+        if not sep:
+            raise ValueError(f"expected KEY=VALUE, got {pair!r}")
+        d[key] = value
+    return d
+
+
+def numbered(d: dict, start: int = 1) -> list[str]:
+    """One "i. key=value" line per entry."""
+    lines = []
+    for i, (k, v) in enumerate(d.items(), start):
+        lines.append(f"{i}. {k}={v}")
+    return lines
+
+
+def even_keys(d: dict) -> list[str]:
+    """Keys at even positions.
+
+    >>> even_keys({"a": 1, "b": 2, "c": 3})
+    ['a', 'c']
+    """
+    result = []
+    for i, (k, _) in enumerate(d.items()):
+        if i % 2 == 0:
+            result.append(k)
+    return result
+
+
+def main():
+    parser = argparse.ArgumentParser(description="Numbered dict entries tool")
+    subs = parser.add_subparsers(dest="cmd", required=True)
+
+    n = subs.add_parser("number")
+    n.add_argument("pairs", nargs="+")
+    n.add_argument("--start", type=int, default=1)
+    e = subs.add_parser("even")
+    e.add_argument("pairs", nargs="+")
+
+    args = parser.parse_args()
+    try:
+        d = parse_pairs(args.pairs)
+    except ValueError as ex:
+        print(f"Error: {ex}", file=sys.stderr)
+        sys.exit(1)
+    if args.cmd == "number":
+        for line in numbered(d, args.start):
+            print(line)
+    elif args.cmd == "even":
+        print(" ".join(even_keys(d)))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,35 @@
+"""Tests for enumerate_items_tool.py"""
+
+from pathlib import Path
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "enumerate_items_tool.py"
+
+run = GoldenCli(SCRIPT).run
+
+
+class TestNumber:
+    def test_numbers_entries(self):
+        stdout, _, code = run(["number", "host=db", "port=5432", "user=ada"])
+        assert code == 0
+        assert stdout == "1. host=db\n2. port=5432\n3. user=ada\n"
+
+    def test_start(self):
+        stdout, _, _ = run(["number", "a=1", "b=2", "--start", "0"])
+        assert stdout == "0. a=1\n1. b=2\n"
+
+    def test_duplicate_key_keeps_first_position(self):
+        stdout, _, _ = run(["number", "a=1", "b=2", "a=3"])
+        assert stdout == "1. a=3\n2. b=2\n"
+
+    def test_bad_pair(self):
+        _, stderr, code = run(["number", "oops"])
+        assert code == 1
+        assert "Error: expected KEY=VALUE" in stderr
+
+
+class TestEven:
+    def test_even(self):
+        stdout, _, _ = run(["even", "a=1", "b=2", "c=3", "d=4", "e=5"])
+        assert stdout.strip() == "a c e"
@@ -0,0 +1,52 @@
+#!/usr/bin/env python3
+"""Env Default Example - Argument defaults read from environment variables CLI.
+
+`default=os.environ.get("X", "y")` is evaluated when the parser is
+built, so the default comes from the environment at run time and an
+explicit flag still wins.
+
+Examples:
+    >>> greeting("Ada", 2)
+    'Hello, Ada! Hello, Ada!'
+    >>> endpoint("example.com", 8080)
+    'example.com:8080'
+"""
+
+import argparse
+import os
+
+
+def greeting(name: str, times: int) -> str:
+    """Repeat a greeting.
+
+    >>> greeting("x", 0)
+    ''
+    """
+    return " ".join(f"Hello, {name}!" for _ in range(times))
+
+
+def endpoint(host: str, port: int) -> str:
+    """Join host and port."""
+    return f"{host}:{port}"
+
+
+def main():
+    parser = argparse.ArgumentParser(description="Environment default tool")
+    subs = parser.add_subparsers(dest="cmd", required=True)
+
+    g = subs.add_parser("greet")
+    g.add_argument("--name", default=os.environ.get("GREET_NAME", "world"))
+    g.add_argument("--times", type=int, default=int(os.environ.get("GREET_TIMES", "1")))
+    e = subs.add_parser("endpoint")
+    e.add_argument("--host", default=os.environ.get("APP_HOST", "localhost"))
+    e.add_argument("--port", type=int, default=int(os.environ.get("APP_PORT", "8000")))
+
+    args = parser.parse_args()
+    if args.cmd == "greet":
+        print(greeting(args.name, args.times))
+    elif args.cmd == "endpoint":
+        print(endpoint(args.host, args.port))
+
+
+if __name__ == "__main__":
+    main()
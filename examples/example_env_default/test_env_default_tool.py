@@ -0,0 +1,47 @@
+"""Tests for env_default_tool.py"""
+
+import os
+import subprocess
+from pathlib import Path
+
+SCRIPT = Path(__file__).parent / "env_default_tool.py"
+
+ENV_VARS = ("GREET_NAME", "GREET_TIMES", "APP_HOST", "APP_PORT")
+
+
+def run(args, **env):
+    """Run the CLI with extra environment variables and return (stdout, stderr, returncode)."""
+    base = {k: v for k, v in os.environ.items() if k not in ENV_VARS}
+    result = subprocess.run(
+        ["python3", str(SCRIPT)] + args,
+        capture_output=True,
+        text=True,
+        env={**base, **env},
+    )
+    return result.stdout, result.stderr, result.returncode
+
+
+class TestFallback:
+    def test_builtin_default(self):
+        stdout, _, code = run(["greet"])
+        assert code == 0
+        assert stdout.strip() == "Hello, world!"
+
+    def test_endpoint_default(self):
+        stdout, _, _ = run(["endpoint"])
+        assert stdout.strip() == "localhost:8000"
+
+
+class TestEnvDefault:
+    def test_env_used_when_flag_omitted(self):
+        stdout, _, code = run(["greet"], GREET_NAME="Ada")
+        assert code == 0
+        assert stdout.strip() == "Hello, Ada!"
+
+    def test_env_int_default(self):
+        stdout, _, _ = run(["greet"], GREET_NAME="Ada", GREET_TIMES="2")
+        assert stdout.strip() == "Hello, Ada! Hello, Ada!"
+
+    def test_flag_overrides_env(self):
+        stdout, _, _ = run(["endpoint", "--port", "9000"], APP_HOST="db", APP_PORT="5432")
+        assert stdout.strip() == "db:9000"
@@ -0,0 +1,82 @@
+#!/usr/bin/env python3
+"""Euclid Example - User-defined helper function CLI.
+
+`gcd` is written out with Euclid's algorithm instead of using
+`math.gcd`, and several subcommands share it: `gcd` prints it, `lcm`
+builds on it and `coprime` tests it against 1.
+
+Examples:
+    >>> gcd(12, 18)
+    6
+    >>> lcm(4, 6)
+    12
+    >>> coprime(8, 15)
+    True
+"""
+
+import argparse
+
+
+def gcd(a: int, b: int) -> int:
+    """Greatest common divisor, always non-negative.
+
+    >>> gcd(-12, 18)
+    6
+    >>> gcd(0, 0)
+    0
+    """
+    a = abs(a)
+    b = abs(b)
+    while b != 0:
+        a, b = b, a % b
+    return a
+
+
+def lcm(a: int, b: int) -> int:
+    """Least common multiple via gcd; 0 when either input is 0.
+
+    >>> lcm(0, 5)
+    0
+    """
+    if a == 0 or b == 0:
+        return 0
+    return abs(a * b) // gcd(a, b)
+
+
+def coprime(a: int, b: int) -> bool:
+    """True when the only common divisor is 1.
+
+    >>> coprime(6, 9)
+    False
+    """
+    return gcd(a, b) == 1
+
+
+def main():
+    parser = argparse.ArgumentParser(description="Euclid gcd tool")
+    subs = parser.add_subparsers(dest="cmd", required=True)
+
+    g = subs.add_parser("gcd")
+    g.add_argument("a", type=int)
+    g.add_argument("b", type=int)
+    lc = subs.add_parser("lcm")
+    lc.add_argument("a", type=int)
+    lc.add_argument("b", type=int)
+    c = subs.add_parser("coprime")
+    c.add_argument("a", type=int)
+    c.add_argument("b", type=int)
+
+    args = parser.parse_args()
+    if args.cmd == "gcd":
+        print(gcd(args.a, args.b))
+    elif args.cmd == "lcm":
+        print(lcm(args.a, args.b))
+    elif args.cmd == "coprime":
+        if coprime(args.a, args.b):
+            print("yes")
+        else:
+            print("no")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,77 @@
+"""Tests for euclid_tool.py"""
+
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "euclid_tool.py"
+
+GOLDEN = GoldenCli(SCRIPT)
+run = GOLDEN.run
+
+
+class TestGcd:
+    @pytest.mark.parametrize(
+        "a,b,expected",
+        [
+            ("12", "18", "6"),
+            ("18", "12", "6"),
+            ("-12", "18", "6"),
+            ("7", "13", "1"),
+            ("0", "5", "5"),
+            ("0", "0", "0"),
+        ],
+    )
+    def test_gcd(self, a, b, expected):
+        stdout, _, code = run(["gcd", a, b])
+        assert code == 0
+        assert stdout == f"{expected}\n"
+
+
+class TestLcm:
+    def test_lcm(self):
+        stdout, _, _ = run(["lcm", "4", "6"])
+        assert stdout == "12\n"
+
+    def test_negative(self):
+        stdout, _, _ = run(["lcm", "-4", "6"])
+        assert stdout == "12\n"
+
+    def test_zero(self):
+        stdout, _, _ = run(["lcm", "0", "6"])
+        assert stdout == "0\n"
+
+
+class TestCoprime:
+    def test_yes(self):
+        stdout, _, _ = run(["coprime", "8", "15"])
+        assert stdout == "yes\n"
+
+    def test_no(self):
+        stdout, _, _ = run(["coprime", "6", "9"])
+        assert stdout == "no\n"
+
+    def test_zero_and_one(self):
+        stdout, _, _ = run(["coprime", "0", "1"])
+        assert stdout == "yes\n"
+
+
+class TestGolden:
+    """One gcd helper serves all three subcommands."""
+
+    @pytest.mark.parametrize(
+        "argv,stdout",
+        [
+            (["gcd", "12", "18"], "6\n"),
+            (["gcd", "-12", "18"], "6\n"),
+            (["gcd", "0", "0"], "0\n"),
+            (["lcm", "4", "6"], "12\n"),
+            (["lcm", "0", "6"], "0\n"),
+            (["coprime", "8", "15"], "yes\n"),
+            (["coprime", "6", "9"], "no\n"),
+        ],
+    )
+    def test_golden(self, argv, stdout):
+        GOLDEN.check(argv, stdout)
@@ -0,0 +1,65 @@
+"""Tests for file_text_cli.py"""
+
+import subprocess
+from pathlib import Path
+
+import pytest
+from file_text_cli import append_text, read_text, write_text
+
+SCRIPT = Path(__file__).parent / "file_text_cli.py"
+
+
+def run_cli(*args):
+    """Helper to run CLI and capture output"""
+    return subprocess.run(["python3", str(SCRIPT), *args], capture_output=True, text=True)
+
+
+class TestRoundTrip:
+    def test_write_then_read(self, tmp_path):
+        p = tmp_path / "note.txt"
+        n = write_text(str(p), "hello\nworld\n")
+        assert n == 12
+        assert read_text(str(p)) == "hello\nworld\n"
+
+    def test_unicode_round_trip(self, tmp_path):
+        p = tmp_path / "uni.txt"
+        write_text(str(p), "héllo ✓")
+        assert read_text(str(p)) == "héllo ✓"
+
+    def test_write_overwrites(self, tmp_path):
+        p = tmp_path / "note.txt"
+        write_text(str(p), "first")
+        write_text(str(p), "second")
+        assert read_text(str(p)) == "second"
+
+    def test_append_creates_missing(self, tmp_path):
+        p = tmp_path / "log.txt"
+        append_text(str(p), "a")
+        append_text(str(p), "b")
+        assert read_text(str(p)) == "ab"
+
+
+class TestErrors:
+    def test_read_missing_raises_ioerror(self, tmp_path):
+        with pytest.raises(IOError):
+            read_text(str(tmp_path / "missing.txt"))
+
+    def test_write_into_missing_dir_raises_ioerror(self, tmp_path):
+        with pytest.raises(IOError):
+            write_text(str(tmp_path / "nope" / "out.txt"), "x")
+
+
+class TestCli:
+    def test_cli_round_trip(self, tmp_path):
+        p = tmp_path / "cli.txt"
+        result = run_cli("write", str(p), "line one")
+        assert result.returncode == 0
+        assert "Wrote 8 bytes" in result.stdout
+        result = run_cli("read", str(p))
+        assert result.returncode == 0
+        assert result.stdout == "line one"
+
+    def test_cli_read_missing_fails(self, tmp_path):
+        result = run_cli("read", str(tmp_path / "missing.txt"))
+        assert result.returncode != 0
+        assert "FileNotFoundError" in result.stderr
@@ -0,0 +1,45 @@
+"""Tests for filter_tool.py"""
+
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "filter_tool.py"
+
+GOLDEN = GoldenCli(SCRIPT)
+run = GOLDEN.run
+
+
+class TestFilter:
+    def test_positive(self):
+        stdout, _, code = run(["positive", "--", "-2", "3", "0", "5", "-1"])
+        assert code == 0
+        assert stdout.strip() == "3 5"
+
+    def test_even(self):
+        stdout, _, _ = run(["even", "1", "2", "3", "4", "5"])
+        assert stdout.strip() == "2 4"
+
+    def test_odd(self):
+        stdout, _, _ = run(["odd", "1", "2", "3", "4", "5"])
+        assert stdout.strip() == "1 3 5"
+
+    def test_none_match(self):
+        stdout, _, _ = run(["even", "1", "3", "5", "7", "9"])
+        assert stdout == "\n"
+
+
+class TestGolden:
+    """result starts as "" and grows by +; it must compile as an owned String"""
+
+    @pytest.mark.parametrize(
+        "argv,stdout",
+        [
+            (["even", "1", "2", "3", "4", "5"], "2 4\n"),
+            (["odd", "1", "2", "3", "4", "5"], "1 3 5\n"),
+        ],
+    )
+    def test_golden(self, argv, stdout):
+        GOLDEN.check(argv, stdout)
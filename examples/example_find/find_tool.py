@@ -14,6 +14,12 @@ def main():
     la = subs.add_parser("last")
     la.add_argument("text")
     la.add_argument("char")
+    fl = subs.add_parser("flag")
+    fl.add_argument("text")
+    fl.add_argument("char")
+    up = subs.add_parser("upto")
+    up.add_argument("text")
+    up.add_argument("char")
 
     args = parser.parse_args()
     if args.cmd == "first":
@@ -33,6 +39,23 @@ def main():
                 result = i
             i = i + 1
         print(result)
+    elif args.cmd == "flag":
+        found = False
+        i = 0
+        while i < len(args.text) and not found:
+            if args.text[i] == args.char:
+                found = True
+            else:
+                i = i + 1
+        if found:
+            print(i)
+        else:
+            print(-1)
+    elif args.cmd == "upto":
+        text = args.text
+        while len(text) > 0 and text[-1] != args.char:
+            text = text[:-1]
+        print(f"[{text}]")
 
 
 if __name__ == "__main__":
@@ -0,0 +1,89 @@
+"""Tests for find_tool.py"""
+
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "find_tool.py"
+
+GOLDEN = GoldenCli(SCRIPT)
+run = GOLDEN.run
+
+
+class TestFind:
+    def test_first(self):
+        stdout, _, code = run(["first", "hello", "l"])
+        assert code == 0
+        assert stdout.strip() == "2"
+
+    def test_last(self):
+        stdout, _, _ = run(["last", "hello", "l"])
+        assert stdout.strip() == "3"
+
+    def test_missing(self):
+        stdout, _, _ = run(["first", "hello", "z"])
+        assert stdout.strip() == "-1"
+
+
+class TestMultibyte:
+    """Indices are character positions, not UTF-8 byte offsets"""
+
+    def test_first_after_accent(self):
+        stdout, _, _ = run(["first", "héllo", "l"])
+        assert stdout.strip() == "2"
+
+    def test_last_multibyte_char(self):
+        stdout, _, _ = run(["last", "añoño", "ñ"])
+        assert stdout.strip() == "3"
+
+
+class TestCompoundCondition:
+    """Both halves of the while condition are re-evaluated every iteration."""
+
+    def test_flag_stops_at_first(self):
+        stdout, _, code = run(["flag", "banana", "n"])
+        assert code == 0
+        assert stdout.strip() == "2"
+
+    def test_flag_missing(self):
+        stdout, _, _ = run(["flag", "banana", "z"])
+        assert stdout.strip() == "-1"
+
+    def test_flag_empty_text(self):
+        stdout, _, _ = run(["flag", "", "a"])
+        assert stdout.strip() == "-1"
+
+    def test_flag_last_position(self):
+        stdout, _, _ = run(["flag", "abc", "c"])
+        assert stdout.strip() == "2"
+
+    def test_upto_shrinks_text(self):
+        stdout, _, _ = run(["upto", "path/to/file.txt", "/"])
+        assert stdout.strip() == "[path/to/]"
+
+    def test_upto_missing_empties(self):
+        stdout, _, _ = run(["upto", "abc", "z"])
+        assert stdout.strip() == "[]"
+
+    def test_upto_already_ends_with_char(self):
+        stdout, _, _ = run(["upto", "abc", "c"])
+        assert stdout.strip() == "[abc]"
+
+
+class TestGolden:
+    @pytest.mark.parametrize(
+        "argv,stdout",
+        [
+            (["first", "héllo", "l"], "2\n"),
+            (["last", "añoño", "ñ"], "3\n"),
+            (["flag", "banana", "n"], "2\n"),
+            (["flag", "banana", "z"], "-1\n"),
+            (["upto", "path/to/file.txt", "/"], "[path/to/]\n"),
+            (["upto", "abc", "z"], "[]\n"),
+            (["upto", "héllo", "é"], "[hé]\n"),
+        ],
+    )
+    def test_golden(self, argv, stdout):
+        GOLDEN.check(argv, stdout)
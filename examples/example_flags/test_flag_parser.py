@@ -10,8 +10,12 @@ from pathlib import Path
 
 import pytest
 
+from reprorusted_python_cli.golden import GoldenCli
+
 SCRIPT = Path(__file__).parent / "flag_parser.py"
 
+GOLDEN = GoldenCli(SCRIPT)
+
 
 def run_cli(*args):
     """Helper to run CLI and capture output"""
@@ -264,3 +268,30 @@ class TestEdgeCases:
         assert "Verbose: True" in result.stdout
         assert "Debug: True" in result.stdout
         assert "Quiet: True" in result.stdout
+
+
+class TestGolden:
+    """End-to-end cases checked against Python and the transpiled binary"""
+
+    @pytest.mark.parametrize(
+        "argv,stdout",
+        [
+            ([], "Verbose: False\nDebug: False\nQuiet: False\n"),
+            (["-v"], "Verbose: True\nDebug: False\nQuiet: False\nVERBOSE MODE ENABLED\n"),
+            (
+                ["--debug", "--quiet"],
+                "Verbose: False\nDebug: True\nQuiet: True\n"
+                "DEBUG MODE ENABLED\nQUIET MODE ENABLED\n",
+            ),
+            (
+                ["-vdq"],
+                "Verbose: True\nDebug: True\nQuiet: True\n"
+                "VERBOSE MODE ENABLED\nDEBUG MODE ENABLED\nQUIET MODE ENABLED\n",
+            ),
+        ],
+    )
+    def test_golden(self, argv, stdout):
+        GOLDEN.check(argv, stdout)
+
+    def test_golden_invalid_flag(self):
+        GOLDEN.check(["--bogus"], stdout="", returncode=2)
@@ -17,6 +17,9 @@ def main():
     c = subs.add_parser("center")
     c.add_argument("text")
     c.add_argument("width", type=int)
+    b = subs.add_parser("banner")
+    b.add_argument("text")
+    b.add_argument("--align", choices=["left", "center", "right"], default="center")
 
     args = parser.parse_args()
     if args.cmd == "padleft":
@@ -39,6 +42,13 @@ def main():
                 result = result + " "
             left = not left
         print(result)
+    elif args.cmd == "banner":
+        if args.align == "left":
+            print(f"{args.text:*<10}")
+        elif args.align == "right":
+            print(f"{args.text:*>10}")
+        else:
+            print(f"{args.text:*^10}")
 
 
 if __name__ == "__main__":
@@ -0,0 +1,73 @@
+"""Tests for format_tool.py"""
+
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "format_tool.py"
+
+GOLDEN = GoldenCli(SCRIPT)
+run = GOLDEN.run
+
+
+class TestPad:
+    def test_padleft(self):
+        stdout, _, code = run(["padleft", "hi", "5"])
+        assert code == 0
+        assert stdout == "   hi\n"
+
+    def test_padright(self):
+        stdout, _, _ = run(["padright", "hi", "5"])
+        assert stdout == "hi   \n"
+
+    def test_center(self):
+        stdout, _, _ = run(["center", "hi", "6"])
+        assert stdout == "  hi  \n"
+
+    def test_already_wide(self):
+        stdout, _, _ = run(["padleft", "hello", "3"])
+        assert stdout == "hello\n"
+
+
+class TestBanner:
+    def test_center(self):
+        stdout, _, code = run(["banner", "hi"])
+        assert code == 0
+        assert stdout == "****hi****\n"
+
+    def test_center_odd_padding(self):
+        stdout, _, _ = run(["banner", "hey"])
+        assert stdout == "***hey****\n"
+
+    def test_left(self):
+        stdout, _, _ = run(["banner", "hi", "--align", "left"])
+        assert stdout == "hi********\n"
+
+    def test_right(self):
+        stdout, _, _ = run(["banner", "hi", "--align", "right"])
+        assert stdout == "********hi\n"
+
+    def test_too_wide(self):
+        stdout, _, _ = run(["banner", "hello world"])
+        assert stdout == "hello world\n"
+
+
+class TestGolden:
+    """Every subcommand arm must bind the fields its body reads"""
+
+    @pytest.mark.parametrize(
+        "argv,stdout",
+        [
+            (["padleft", "hi", "5"], "   hi\n"),
+            (["padright", "hi", "5"], "hi   \n"),
+            (["center", "hi", "5"], "  hi \n"),
+            (["banner", "hi"], "****hi****\n"),
+            (["banner", "hey"], "***hey****\n"),
+            (["banner", "hi", "--align", "left"], "hi********\n"),
+            (["banner", "hi", "--align", "right"], "********hi\n"),
+        ],
+    )
+    def test_golden(self, argv, stdout):
+        GOLDEN.check(argv, stdout)
@@ -0,0 +1,77 @@
+#!/usr/bin/env python3
+"""Format Fields Example - Field access inside str.format CLI.
+
+Replacement fields can reach into their arguments: `{0.name}` reads an
+attribute of the first positional argument and `{d[host]}` subscripts a
+keyword argument. Subscript keys are written bare, without quotes.
+
+Examples:
+    >>> describe(User("ada", 36))
+    'ada is 36'
+    >>> endpoint({"host": "db", "port": "5432"})
+    'db:5432'
+"""
+
+import argparse
+from dataclasses import dataclass
+
+
+@dataclass
+class User:
+    name: str
+    age: int
+
+
+def describe(user: User) -> str:
+    """Attribute access on a positional argument.
+
+    >>> describe(User("bob", 7))
+    'bob is 7'
+    """
+    return "{0.name} is {0.age}".format(user)
+
+
+def endpoint(config: dict[str, str]) -> str:
+    """Subscript access on a keyword argument.
+
+    >>> endpoint({"host": "localhost", "port": "80"})
+    'localhost:80'
+    """
+    return "{d[host]}:{d[port]}".format(d=config)
+
+
+def badge(user: User, config: dict[str, str]) -> str:
+    """Both forms in one template, with a format spec on the field.
+
+    >>> badge(User("ada", 36), {"team": "core"})
+    '[core] ada       (36)'
+    """
+    return "[{c[team]}] {u.name:<9} ({u.age})".format(u=user, c=config)
+
+
+def main():
+    parser = argparse.ArgumentParser(description="Format field access tool")
+    subs = parser.add_subparsers(dest="cmd", required=True)
+
+    u = subs.add_parser("user")
+    u.add_argument("name")
+    u.add_argument("age", type=int)
+    e = subs.add_parser("endpoint")
+    e.add_argument("host")
+    e.add_argument("port")
+    b = subs.add_parser("badge")
+    b.add_argument("name")
+    b.add_argument("age", type=int)
+    b.add_argument("team")
+
+    args = parser.parse_args()
+    if args.cmd == "user":
+        print(describe(User(args.name, args.age)))
+    elif args.cmd == "endpoint":
+        print(endpoint({"host": args.host, "port": args.port}))
+    elif args.cmd == "badge":
+        print(badge(User(args.name, args.age), {"team": args.team}))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,24 @@
+"""Tests for format_fields_tool.py"""
+
+from pathlib import Path
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "format_fields_tool.py"
+
+run = GoldenCli(SCRIPT).run
+
+
+class TestFieldAccess:
+    def test_struct_field(self):
+        stdout, _, code = run(["user", "ada", "36"])
+        assert code == 0
+        assert stdout.strip() == "ada is 36"
+
+    def test_dict_value(self):
+        stdout, _, _ = run(["endpoint", "db.local", "5432"])
+        assert stdout.strip() == "db.local:5432"
+
+    def test_mixed_with_spec(self):
+        stdout, _, _ = run(["badge", "grace", "45", "infra"])
+        assert stdout == "[infra] grace     (45)\n"
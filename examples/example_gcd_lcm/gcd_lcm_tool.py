@@ -0,0 +1,86 @@
+#!/usr/bin/env python3
+"""GCD LCM Example - math.gcd and math.lcm CLI.
+
+Both results are always non-negative, whatever the signs of the inputs.
+`gcd(0, 0)` is 0, and `lcm` is 0 whenever any input is 0. Both accept
+any number of arguments (Python 3.9+).
+
+Examples:
+    >>> math.gcd(12, 18)
+    6
+    >>> math.lcm(4, 6)
+    12
+    >>> math.gcd(-12, 18)
+    6
+"""
+
+import argparse
+import math
+import sys
+
+
+def gcd_all(nums: list[int]) -> int:
+    """GCD of every number.
+
+    >>> gcd_all([12, 18, 27])
+    3
+    >>> gcd_all([0, 0])
+    0
+    >>> gcd_all([0, -5])
+    5
+    """
+    return math.gcd(*nums)
+
+
+def lcm_all(nums: list[int]) -> int:
+    """LCM of every number.
+
+    >>> lcm_all([2, 3, 4])
+    12
+    >>> lcm_all([-4, 6])
+    12
+    >>> lcm_all([5, 0])
+    0
+    """
+    return math.lcm(*nums)
+
+
+def reduce_fraction(num: int, den: int) -> tuple[int, int]:
+    """Fraction in lowest terms with a positive denominator.
+
+    >>> reduce_fraction(6, -8)
+    (-3, 4)
+    """
+    g = math.gcd(num, den)
+    if den < 0:
+        g = -g
+    return num // g, den // g
+
+
+def main():
+    parser = argparse.ArgumentParser(description="GCD and LCM tool")
+    subs = parser.add_subparsers(dest="cmd", required=True)
+
+    g = subs.add_parser("gcd")
+    g.add_argument("nums", type=int, nargs="+")
+    lc = subs.add_parser("lcm")
+    lc.add_argument("nums", type=int, nargs="+")
+    r = subs.add_parser("reduce")
+    r.add_argument("num", type=int)
+    r.add_argument("den", type=int)
+
+    args = parser.parse_args()
+    if args.cmd == "gcd":
+        print(gcd_all(args.nums))
+    elif args.cmd == "lcm":
+        print(lcm_all(args.nums))
+    elif args.cmd == "reduce":
+        if args.den == 0:
+            print("Error: zero denominator", file=sys.stderr)
+            sys.exit(1)
+        num, den = reduce_fraction(args.num, args.den)
+        print(f"{num}/{den}")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,56 @@
+"""Tests for gcd_lcm_tool.py"""
+
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "gcd_lcm_tool.py"
+
+run = GoldenCli(SCRIPT).run
+
+
+class TestGcd:
+    @pytest.mark.parametrize(
+        "nums,expected",
+        [
+            (["12", "18"], "6"),
+            (["17", "5"], "1"),
+            (["0", "9"], "9"),
+            (["0", "0"], "0"),
+            (["-12", "18"], "6"),
+            (["24", "36", "60"], "12"),
+        ],
+    )
+    def test_gcd(self, nums, expected):
+        stdout, _, code = run(["gcd"] + nums)
+        assert code == 0
+        assert stdout.strip() == expected
+
+
+class TestLcm:
+    @pytest.mark.parametrize(
+        "nums,expected",
+        [
+            (["4", "6"], "12"),
+            (["21", "6"], "42"),
+            (["0", "5"], "0"),
+            (["-4", "6"], "12"),
+            (["2", "3", "4", "5"], "60"),
+        ],
+    )
+    def test_lcm(self, nums, expected):
+        stdout, _, _ = run(["lcm"] + nums)
+        assert stdout.strip() == expected
+
+
+class TestReduce:
+    def test_reduce(self):
+        stdout, _, _ = run(["reduce", "6", "-8"])
+        assert stdout.strip() == "-3/4"
+
+    def test_zero_denominator(self):
+        _, stderr, code = run(["reduce", "1", "0"])
+        assert code == 1
+        assert "Error: zero denominator" in stderr
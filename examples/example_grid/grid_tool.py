@@ -0,0 +1,154 @@
+#!/usr/bin/env python3
+"""Grid Example - 2D lists and nested indexing CLI.
+
+Covers list-of-list literals, nested comprehensions, nested
+`for i in range(rows): for j in range(cols):` loops, and reading and
+writing cells with `grid[i][j]`.
+
+Examples:
+    >>> times_table(3)
+    [[1, 2, 3], [2, 4, 6], [3, 6, 9]]
+    >>> mark(2, 3, 1, 2)
+    [[0, 0, 0], [0, 0, 1]]
+"""
+
+import argparse
+import sys
+
+
+def times_table(n: int) -> list[list[int]]:
+    """n x n multiplication table as a nested list.
+
+    >>> times_table(1)
+    [[1]]
+    """
+    return [[(i + 1) * (j + 1) for j in range(n)] for i in range(n)]
+
+
+def mark(rows: int, cols: int, r: int, c: int) -> list[list[int]]:
+    """Zero grid with a single cell set to 1.
+
+    >>> mark(1, 1, 0, 0)
+    [[1]]
+    >>> mark(2, 2, -1, 0)
+    Traceback (most recent call last):
+        ...
+    ValueError: row -1 out of range for 2 rows
+    """
+    if not 0 <= r < rows:
+        raise ValueError(f"row {r} out of range for {rows} rows")
+    if not 0 <= c < cols:
+        raise ValueError(f"column {c} out of range for {cols} columns")
+    grid = [[0 for _ in range(cols)] for _ in range(rows)]
+    grid[r][c] = 1
+    return grid
+
+
+def diagonal(grid: list[list[int]]) -> list[int]:
+    """Main diagonal of a square grid.
+
+    >>> diagonal([[1, 2], [3, 4]])
+    [1, 4]
+    """
+    result = []
+    for i in range(len(grid)):
+        result.append(grid[i][i])
+    return result
+
+
+def parse_matrix(rows: list[str]) -> list[list[int]]:
+    """Matrix from whitespace-separated rows, which must all be the same length.
+
+    >>> parse_matrix(["1 2", "3 4"])
+    [[1, 2], [3, 4]]
+    >>> parse_matrix(["1 2", "3"])
+    Traceback (most recent call last):
+        ...
+    ValueError: ragged matrix: row 2 has 1 values, expected 2
+    """
+    grid = [[int(x) for x in row.split()] for row in rows]
+    for i in range(len(grid)):
+        if len(grid[i]) != len(grid[0]):
+            raise ValueError(
+                f"ragged matrix: row {i + 1} has {len(grid[i])} values, expected {len(grid[0])}"
+            )
+    return grid
+
+
+def diagonal_sum(grid: list[list[int]]) -> int:
+    """Sum of cells where the row index equals the column index.
+
+    >>> diagonal_sum([[1, 2, 3], [4, 5, 6], [7, 8, 9]])
+    15
+    >>> diagonal_sum([[1, 2, 3], [4, 5, 6]])
+    6
+    """
+    total = 0
+    for i in range(len(grid)):
+        for j in range(len(grid[0])):
+            if i == j:
+                total = total + grid[i][j]
+    return total
+
+
+def grid_sum(grid: list[list[int]]) -> int:
+    """Sum of every cell.
+
+    >>> grid_sum([[1, 2], [3, 4]])
+    10
+    """
+    total = 0
+    for i in range(len(grid)):
+        for j in range(len(grid[i])):
+            total = total + grid[i][j]
+    return total
+
+
+def render(grid: list[list[int]], width: int) -> str:
+    """Right-aligned rows, one per line.
+
+    >>> print(render([[1, 2], [3, 4]], 2))
+     1  2
+     3  4
+    """
+    return "\n".join(" ".join(str(x).rjust(width) for x in row) for row in grid)
+
+
+def main():
+    parser = argparse.ArgumentParser(description="2D grid tool")
+    subs = parser.add_subparsers(dest="cmd", required=True)
+
+    t = subs.add_parser("table")
+    t.add_argument("n", type=int)
+    m = subs.add_parser("mark")
+    m.add_argument("rows", type=int)
+    m.add_argument("cols", type=int)
+    m.add_argument("r", type=int)
+    m.add_argument("c", type=int)
+    d = subs.add_parser("diag")
+    d.add_argument("n", type=int)
+    tr = subs.add_parser("trace")
+    tr.add_argument("rows", nargs="+")
+    to = subs.add_parser("total")
+    to.add_argument("rows", nargs="+")
+
+    args = parser.parse_args()
+    try:
+        if args.cmd == "table":
+            grid = times_table(args.n)
+            print(render(grid, len(str(grid[-1][-1])) if grid else 1))
+        elif args.cmd == "mark":
+            print(render(mark(args.rows, args.cols, args.r, args.c), 1))
+        elif args.cmd == "diag":
+            print(" ".join(str(x) for x in diagonal(times_table(args.n))))
+        elif args.cmd == "trace":
+            print(diagonal_sum(parse_matrix(args.rows)))
+        elif args.cmd == "total":
+            print(grid_sum(parse_matrix(args.rows)))
+    except ValueError as e:
+        print(f"Error: {e}", file=sys.stderr)
+        sys.exit(1)
+
+
+if __name__ == "__main__":
+    main()
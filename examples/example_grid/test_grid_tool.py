@@ -0,0 +1,106 @@
+"""Tests for grid_tool.py"""
+
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "grid_tool.py"
+
+GOLDEN = GoldenCli(SCRIPT)
+run = GOLDEN.run
+
+
+class TestTable:
+    def test_multiplication_table(self):
+        stdout, _, code = run(["table", "4"])
+        assert code == 0
+        assert stdout == (
+            " 1  2  3  4\n"
+            " 2  4  6  8\n"
+            " 3  6  9 12\n"
+            " 4  8 12 16\n"
+        )
+
+    def test_single_digit(self):
+        stdout, _, _ = run(["table", "2"])
+        assert stdout == "1 2\n2 4\n"
+
+
+class TestNestedWrite:
+    def test_mark(self):
+        stdout, _, code = run(["mark", "2", "3", "1", "0"])
+        assert code == 0
+        assert stdout == "0 0 0\n1 0 0\n"
+
+    @pytest.mark.parametrize(
+        "argv,message",
+        [
+            (["2", "3", "2", "0"], "row 2 out of range for 2 rows"),
+            (["2", "3", "-1", "0"], "row -1 out of range for 2 rows"),
+            (["2", "3", "0", "3"], "column 3 out of range for 3 columns"),
+            (["2", "3", "0", "-1"], "column -1 out of range for 3 columns"),
+        ],
+    )
+    def test_out_of_range(self, argv, message):
+        stdout, stderr, code = run(["mark"] + argv)
+        assert code == 1
+        assert stdout == ""
+        assert f"Error: {message}" in stderr
+
+
+class TestNestedRead:
+    def test_diagonal(self):
+        stdout, _, _ = run(["diag", "4"])
+        assert stdout.strip() == "1 4 9 16"
+
+
+class TestNestedLoops:
+    def test_trace(self):
+        stdout, _, code = run(["trace", "1 2 3", "4 5 6", "7 8 9"])
+        assert code == 0
+        assert stdout.strip() == "15"
+
+    def test_trace_non_square(self):
+        stdout, _, _ = run(["trace", "1 2 3", "4 5 6"])
+        assert stdout.strip() == "6"
+
+    def test_trace_negative(self):
+        stdout, _, _ = run(["trace", "-1 0", "0 -2"])
+        assert stdout.strip() == "-3"
+
+    def test_total(self):
+        stdout, _, _ = run(["total", "1 2", "3 4", "5 6"])
+        assert stdout.strip() == "21"
+
+    def test_ragged(self):
+        stdout, stderr, code = run(["trace", "1 2", "3"])
+        assert code == 1
+        assert stdout == ""
+        assert "Error: ragged matrix: row 2 has 1 values, expected 2" in stderr
+
+    def test_not_a_number(self):
+        _, stderr, code = run(["total", "1 x"])
+        assert code == 1
+        assert "Error:" in stderr
+
+
+class TestGolden:
+    """Nested range loops must index grid[i][j] row-major."""
+
+    @pytest.mark.parametrize(
+        "argv,stdout",
+        [
+            (["trace", "1 2 3", "4 5 6", "7 8 9"], "15\n"),
+            (["trace", "1 2 3", "4 5 6"], "6\n"),
+            (["total", "1 2", "3 4", "5 6"], "21\n"),
+            (["diag", "4"], "1 4 9 16\n"),
+        ],
+    )
+    def test_golden(self, argv, stdout):
+        GOLDEN.check(argv, stdout)
+
+    @pytest.mark.parametrize("cell", [["2", "0"], ["-1", "0"], ["0", "3"]])
+    def test_golden_mark_out_of_range(self, cell):
+        GOLDEN.check(["mark", "2", "3"] + cell, stdout="", returncode=1)
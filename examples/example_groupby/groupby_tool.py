@@ -0,0 +1,69 @@
+#!/usr/bin/env python3
+"""Groupby Example - Grouping consecutive equal keys CLI.
+
+`itertools.groupby` yields `(key, group)` pairs for each run of
+consecutive items sharing a key. Non-adjacent equal keys form
+separate groups, which is what run-length style CLIs rely on.
+
+Examples:
+    >>> runs("aaabccaa")
+    [('a', 3), ('b', 1), ('c', 2), ('a', 2)]
+    >>> by_length(["a", "b", "cc", "dd", "e"])
+    [(1, 'a,b'), (2, 'cc,dd'), (1, 'e')]
+"""
+
+import argparse
+from itertools import groupby
+
+
+def runs(text: str) -> list[tuple[str, int]]:
+    """Consecutive equal characters and their run lengths.
+
+    >>> runs("")
+    []
+    """
+    return [(ch, len(list(group))) for ch, group in groupby(text)]
+
+
+def encode(text: str) -> str:
+    """Run-length encode a string.
+
+    >>> encode("aaabcc")
+    'a3b1c2'
+    """
+    return "".join(f"{ch}{n}" for ch, n in runs(text))
+
+
+def by_length(words: list[str]) -> list[tuple[int, str]]:
+    """Group consecutive words by length using a key function.
+
+    >>> by_length(["xy"])
+    [(2, 'xy')]
+    """
+    return [(size, ",".join(group)) for size, group in groupby(words, key=len)]
+
+
+def main():
+    parser = argparse.ArgumentParser(description="Consecutive grouping tool")
+    subs = parser.add_subparsers(dest="cmd", required=True)
+
+    r = subs.add_parser("runs")
+    r.add_argument("text")
+    e = subs.add_parser("encode")
+    e.add_argument("text")
+    b = subs.add_parser("bylen")
+    b.add_argument("words", nargs="+")
+
+    args = parser.parse_args()
+    if args.cmd == "runs":
+        for ch, n in runs(args.text):
+            print(f"{ch} {n}")
+    elif args.cmd == "encode":
+        print(encode(args.text))
+    elif args.cmd == "bylen":
+        for size, group in by_length(args.words):
+            print(f"{size}: {group}")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,33 @@
+"""Tests for groupby_tool.py"""
+
+from pathlib import Path
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "groupby_tool.py"
+
+run = GoldenCli(SCRIPT).run
+
+
+class TestRuns:
+    def test_consecutive_equal_characters(self):
+        stdout, _, code = run(["runs", "aaabccaa"])
+        assert code == 0
+        assert stdout == "a 3\nb 1\nc 2\na 2\n"
+
+    def test_single_run(self):
+        stdout, _, _ = run(["runs", "zzzz"])
+        assert stdout == "z 4\n"
+
+
+class TestEncode:
+    def test_encode(self):
+        stdout, _, _ = run(["encode", "hello"])
+        assert stdout.strip() == "h1e1l2o1"
+
+
+class TestByLength:
+    def test_key_function(self):
+        stdout, _, code = run(["bylen", "a", "b", "cc", "dd", "e"])
+        assert code == 0
+        assert stdout == "1: a,b\n2: cc,dd\n1: e\n"
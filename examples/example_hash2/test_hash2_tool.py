@@ -0,0 +1,69 @@
+"""Tests for hash2_tool.py"""
+
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "hash2_tool.py"
+
+GOLDEN = GoldenCli(SCRIPT)
+run = GOLDEN.run
+
+# 50 characters, long enough to push the accumulators far past 32 bits
+LONG = "the quick brown fox jumps over the lazy dog 012345"
+
+
+class TestDjb2:
+    def test_hello(self):
+        stdout, _, code = run(["djb2", "hello"])
+        assert code == 0
+        assert stdout.strip() == "261238937"
+
+    def test_empty(self):
+        stdout, _, _ = run(["djb2", ""])
+        assert stdout.strip() == "5381"
+
+    def test_long(self):
+        stdout, _, _ = run(["djb2", LONG])
+        assert stdout.strip() == "2073217293"
+
+
+class TestFnv:
+    def test_hello(self):
+        stdout, _, code = run(["fnv", "hello"])
+        assert code == 0
+        assert stdout.strip() == "3069866343"
+
+    def test_empty(self):
+        stdout, _, _ = run(["fnv", ""])
+        assert stdout.strip() == "2166136261"
+
+    def test_long(self):
+        stdout, _, _ = run(["fnv", LONG])
+        assert stdout.strip() == "2646962363"
+
+
+class TestSimple:
+    def test_long(self):
+        stdout, _, _ = run(["simple", LONG])
+        assert stdout.strip() == "4424"
+
+
+class TestGolden:
+    """Hash accumulators must wrap at 32 bits like Python's mod 2**32."""
+
+    @pytest.mark.parametrize(
+        "argv,stdout",
+        [
+            (["djb2", "hello"], "261238937\n"),
+            (["djb2", LONG], "2073217293\n"),
+            (["djb2", "x" * 50], "3747940021\n"),
+            (["fnv", "hello"], "3069866343\n"),
+            (["fnv", LONG], "2646962363\n"),
+            (["fnv", "x" * 50], "3643114909\n"),
+        ],
+    )
+    def test_golden(self, argv, stdout):
+        GOLDEN.check(argv, stdout)
@@ -2,6 +2,7 @@
 """Hex Oct Example - Number base conversion CLI."""
 
 import argparse
+import sys
 
 
 def main():
@@ -14,6 +15,8 @@ def main():
     o.add_argument("num", type=int)
     d = subs.add_parser("dec")
     d.add_argument("hexval")
+    a = subs.add_parser("auto")
+    a.add_argument("text")
 
     args = parser.parse_args()
     if args.cmd == "hex":
@@ -22,6 +25,12 @@ def main():
         print(format(args.num, "o"))
     elif args.cmd == "dec":
         print(int(args.hexval, 16))
+    elif args.cmd == "auto":
+        try:
+            print(int(args.text, 0))
+        except ValueError as e:
+            print(f"Error: {e}", file=sys.stderr)
+            sys.exit(1)
 
 
 if __name__ == "__main__":
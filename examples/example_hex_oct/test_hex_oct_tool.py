@@ -0,0 +1,74 @@
+"""Tests for hex_oct_tool.py"""
+
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "hex_oct_tool.py"
+
+GOLDEN = GoldenCli(SCRIPT)
+run = GOLDEN.run
+
+
+class TestFormat:
+    def test_hex(self):
+        stdout, _, code = run(["hex", "255"])
+        assert code == 0
+        assert stdout.strip() == "ff"
+
+    def test_oct(self):
+        stdout, _, _ = run(["oct", "8"])
+        assert stdout.strip() == "10"
+
+    def test_dec(self):
+        stdout, _, _ = run(["dec", "ff"])
+        assert stdout.strip() == "255"
+
+
+class TestAutoBase:
+    @pytest.mark.parametrize(
+        "text,expected",
+        [
+            ("0x1f", "31"),
+            ("0X1F", "31"),
+            ("0b101", "5"),
+            ("0o17", "15"),
+            ("42", "42"),
+            ("0", "0"),
+            ("1_000", "1000"),
+        ],
+    )
+    def test_prefix(self, text, expected):
+        stdout, _, code = run(["auto", text])
+        assert code == 0
+        assert stdout.strip() == expected
+
+    def test_negative(self):
+        stdout, _, _ = run(["auto", "--", "-0x10"])
+        assert stdout.strip() == "-16"
+
+    @pytest.mark.parametrize("text", ["0xZZ", "0b102", "012", "", "0x"])
+    def test_malformed(self, text):
+        _, stderr, code = run(["auto", text])
+        assert code == 1
+        assert "Error: invalid literal for int() with base 0" in stderr
+
+
+class TestGolden:
+    """format(n, spec) must lower to the matching Rust radix format"""
+
+    @pytest.mark.parametrize(
+        "argv,stdout",
+        [
+            (["hex", "255"], "ff\n"),
+            (["oct", "8"], "10\n"),
+            (["auto", "0x1f"], "31\n"),
+            (["auto", "0b101"], "5\n"),
+            (["auto", "0o17"], "15\n"),
+            (["auto", "42"], "42\n"),
+        ],
+    )
+    def test_golden(self, argv, stdout):
+        GOLDEN.check(argv, stdout)
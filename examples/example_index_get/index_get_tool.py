@@ -0,0 +1,69 @@
+#!/usr/bin/env python3
+"""Index Get Example - Reading list elements by index CLI.
+
+Reading `xs[i]` outside `-len(xs) <= i < len(xs)` raises IndexError;
+it never falls back to an empty or zero value. The error reaches main,
+which reports it and exits 1.
+
+Examples:
+    >>> get(["a", "b", "c"], 1)
+    'b'
+    >>> get(["a", "b", "c"], -1)
+    'c'
+"""
+
+import argparse
+import sys
+
+
+def get(items: list[str], i: int) -> str:
+    """Element at index i, counting from the end when negative.
+
+    >>> get(["x"], 0)
+    'x'
+    >>> get(["x"], 1)
+    Traceback (most recent call last):
+        ...
+    IndexError: list index out of range
+    >>> get([], 0)
+    Traceback (most recent call last):
+        ...
+    IndexError: list index out of range
+    """
+    return items[i]
+
+
+def span(items: list[str], i: int, j: int) -> str:
+    """Elements i and j joined with a dash; both must be in range.
+
+    >>> span(["a", "b", "c"], 0, 2)
+    'a-c'
+    """
+    return items[i] + "-" + items[j]
+
+
+def main():
+    parser = argparse.ArgumentParser(description="Index read tool")
+    subs = parser.add_subparsers(dest="cmd", required=True)
+
+    g = subs.add_parser("get")
+    g.add_argument("index", type=int)
+    g.add_argument("items", nargs="*")
+    s = subs.add_parser("span")
+    s.add_argument("i", type=int)
+    s.add_argument("j", type=int)
+    s.add_argument("items", nargs="*")
+
+    args = parser.parse_args()
+    try:
+        if args.cmd == "get":
+            print(get(args.items, args.index))
+        elif args.cmd == "span":
+            print(span(args.items, args.i, args.j))
+    except IndexError as e:
+        print(f"Error: {e}", file=sys.stderr)
+        sys.exit(1)
+
+
+if __name__ == "__main__":
+    main()
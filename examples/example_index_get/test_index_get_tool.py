@@ -0,0 +1,90 @@
+"""Tests for index_get_tool.py"""
+
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "index_get_tool.py"
+
+GOLDEN = GoldenCli(SCRIPT)
+run = GOLDEN.run
+
+# The same source built so out-of-range reads raise IndexError
+CHECKED = GoldenCli(SCRIPT, bounds="checked")
+
+
+class TestGet:
+    def test_in_range(self):
+        stdout, _, code = run(["get", "1", "a", "b", "c"])
+        assert code == 0
+        assert stdout == "b\n"
+
+    def test_negative(self):
+        stdout, _, _ = run(["get", "-3", "a", "b", "c"])
+        assert stdout == "a\n"
+
+    @pytest.mark.parametrize("index", ["3", "-4", "100"])
+    def test_out_of_range(self, index):
+        stdout, stderr, code = run(["get", index, "a", "b", "c"])
+        assert code == 1
+        assert stdout == ""
+        assert stderr.strip() == "Error: list index out of range"
+
+    def test_empty_list(self):
+        _, stderr, code = run(["get", "0"])
+        assert code == 1
+        assert "IndexError" not in stderr
+        assert "list index out of range" in stderr
+
+
+class TestSpan:
+    def test_span(self):
+        stdout, _, _ = run(["span", "0", "-1", "a", "b", "c"])
+        assert stdout == "a-c\n"
+
+    def test_second_index_out_of_range(self):
+        stdout, _, code = run(["span", "0", "5", "a", "b"])
+        assert code == 1
+        assert stdout == ""
+
+
+class TestGolden:
+    """Out-of-range reads must fail, not print a default value."""
+
+    @pytest.mark.parametrize(
+        "argv,stdout",
+        [
+            (["get", "1", "a", "b", "c"], "b\n"),
+            (["get", "-1", "a", "b", "c"], "c\n"),
+            (["span", "0", "2", "a", "b", "c"], "a-c\n"),
+        ],
+    )
+    def test_golden(self, argv, stdout):
+        GOLDEN.check(argv, stdout)
+
+
+class TestCheckedBounds:
+    """With --bounds=checked, IndexError propagates through main's Result."""
+
+    @pytest.mark.parametrize(
+        "argv",
+        [["get", "3", "a", "b", "c"], ["get", "0"], ["span", "0", "5", "a", "b"]],
+    )
+    def test_out_of_range_exits_1(self, argv):
+        CHECKED.check(argv, stdout="", returncode=1)
+
+    def test_rust_reports_index_error(self):
+        if not CHECKED.has_rust:
+            pytest.skip(CHECKED.skip_reason)
+        result = CHECKED.run_rust(["get", "3", "a", "b", "c"])
+        assert "index out of range" in result.stderr
+
+    def test_no_default_fallback(self):
+        source = CHECKED.rust_source_or_skip()
+        assert "IndexError" in source
+        assert "unwrap_or_default()" not in source
+
+    def test_in_range(self):
+        CHECKED.check(["get", "0", "a"], stdout="a\n")
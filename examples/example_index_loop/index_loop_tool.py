@@ -0,0 +1,119 @@
+#!/usr/bin/env python3
+"""Index Loop Example - range(len(xs)) iteration CLI.
+
+`total`, `largest` and `joined` only read `xs[i]`, so each loop is
+equivalent to iterating the values directly. `doubled` and `swapped`
+write through the index and must keep it.
+
+Examples:
+    >>> total([1, 2, 3])
+    6
+    >>> doubled([1, 2, 3])
+    [2, 4, 6]
+"""
+
+import argparse
+
+
+def total(xs: list[int]) -> int:
+    """Sum via a read-only index loop.
+
+    >>> total([])
+    0
+    >>> total([5, -2, 7])
+    10
+    """
+    s = 0
+    for i in range(len(xs)):
+        s = s + xs[i]
+    return s
+
+
+def largest(xs: list[int]) -> int:
+    """Maximum via a read-only index loop.
+
+    >>> largest([3, 9, 4])
+    9
+    >>> largest([-5, -1, -7])
+    -1
+    """
+    best = xs[0]
+    for i in range(len(xs)):
+        if xs[i] > best:
+            best = xs[i]
+    return best
+
+
+def joined(words: list[str]) -> str:
+    """Concatenate with dashes via a read-only index loop.
+
+    >>> joined(["a", "b", "c"])
+    'a-b-c'
+    """
+    result = ""
+    for i in range(len(words)):
+        if result != "":
+            result = result + "-"
+        result = result + words[i]
+    return result
+
+
+def doubled(xs: list[int]) -> list[int]:
+    """Double every element in place through its index.
+
+    >>> doubled([])
+    []
+    >>> doubled([0, -4])
+    [0, -8]
+    """
+    for i in range(len(xs)):
+        xs[i] = xs[i] * 2
+    return xs
+
+
+def swapped(xs: list[int]) -> list[int]:
+    """Swap each adjacent pair in place; the index is used as i and i + 1.
+
+    >>> swapped([1, 2, 3, 4])
+    [2, 1, 4, 3]
+    >>> swapped([1, 2, 3])
+    [2, 1, 3]
+    """
+    for i in range(len(xs)):
+        if i % 2 == 0 and i + 1 < len(xs):
+            tmp = xs[i]
+            xs[i] = xs[i + 1]
+            xs[i + 1] = tmp
+    return xs
+
+
+def main():
+    parser = argparse.ArgumentParser(description="Index loop tool")
+    subs = parser.add_subparsers(dest="cmd", required=True)
+
+    t = subs.add_parser("total")
+    t.add_argument("nums", type=int, nargs="+")
+    m = subs.add_parser("largest")
+    m.add_argument("nums", type=int, nargs="+")
+    j = subs.add_parser("join")
+    j.add_argument("words", nargs="+")
+    d = subs.add_parser("double")
+    d.add_argument("nums", type=int, nargs="+")
+    s = subs.add_parser("swap")
+    s.add_argument("nums", type=int, nargs="+")
+
+    args = parser.parse_args()
+    if args.cmd == "total":
+        print(total(args.nums))
+    elif args.cmd == "largest":
+        print(largest(args.nums))
+    elif args.cmd == "join":
+        print(joined(args.words))
+    elif args.cmd == "double":
+        print(" ".join(str(x) for x in doubled(args.nums)))
+    elif args.cmd == "swap":
+        print(" ".join(str(x) for x in swapped(args.nums)))
+
+
+if __name__ == "__main__":
+    main()
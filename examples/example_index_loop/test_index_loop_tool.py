@@ -0,0 +1,105 @@
+"""Tests for index_loop_tool.py
+
+TestRewrite inspects the generated Rust and is skipped without depyler.
+"""
+
+import re
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "index_loop_tool.py"
+
+GOLDEN = GoldenCli(SCRIPT)
+run = GOLDEN.run
+
+# `for i in 0..xs.len()`, the literal lowering of `for i in range(len(xs))`
+INDEX_LOOP = re.compile(r"for\s+i\s+in\s+0\s*\.\.\s*\w+\.len\(\)")
+
+# `for x in &xs`, `for x in xs.iter()` and the like
+DIRECT_LOOP = re.compile(r"for\s+\w+\s+in\s+&?\w+(\.iter\(\))?\s*\{")
+
+
+def rust_fn(source, name):
+    """Body of the generated Rust function `name`, matched by braces."""
+    match = re.search(rf"\bfn\s+{name}\b[^{{]*\{{", source)
+    assert match, f"fn {name} not found in generated source"
+    depth = 0
+    for pos in range(match.end() - 1, len(source)):
+        if source[pos] == "{":
+            depth += 1
+        elif source[pos] == "}":
+            depth -= 1
+            if depth == 0:
+                return source[match.end() : pos]
+    raise AssertionError(f"unbalanced braces in fn {name}")
+
+
+@pytest.fixture(scope="module")
+def rust_source():
+    """Generated Rust for the tool, transpiled once per module."""
+    return GOLDEN.rust_source_or_skip()
+
+
+class TestReadOnlyLoops:
+    def test_total(self):
+        stdout, _, code = run(["total", "1", "2", "3", "4"])
+        assert code == 0
+        assert stdout.strip() == "10"
+
+    def test_largest(self):
+        stdout, _, _ = run(["largest", "4", "11", "-3"])
+        assert stdout.strip() == "11"
+
+    def test_join(self):
+        stdout, _, _ = run(["join", "x", "y", "z"])
+        assert stdout.strip() == "x-y-z"
+
+
+class TestMutatingLoops:
+    def test_double_in_place(self):
+        stdout, _, code = run(["double", "1", "2", "3"])
+        assert code == 0
+        assert stdout.strip() == "2 4 6"
+
+    def test_swap_even(self):
+        stdout, _, _ = run(["swap", "1", "2", "3", "4"])
+        assert stdout.strip() == "2 1 4 3"
+
+    def test_swap_odd_leaves_last(self):
+        stdout, _, _ = run(["swap", "1", "2", "3"])
+        assert stdout.strip() == "2 1 3"
+
+
+class TestRewrite:
+    @pytest.mark.parametrize("name", ["total", "largest", "joined"])
+    def test_read_only_loop_iterates_directly(self, rust_source, name):
+        body = rust_fn(rust_source, name)
+        assert INDEX_LOOP.search(body) is None
+        assert DIRECT_LOOP.search(body)
+
+    @pytest.mark.parametrize("name", ["doubled", "swapped"])
+    def test_mutating_loop_keeps_index(self, rust_source, name):
+        assert INDEX_LOOP.search(rust_fn(rust_source, name))
+
+
+class TestGolden:
+    """Rewritten and index-keeping loops must both match Python."""
+
+    @pytest.mark.parametrize(
+        "argv,stdout",
+        [
+            (["total", "1", "2", "3", "4"], "10\n"),
+            (["total", "5", "-2", "7"], "10\n"),
+            (["largest", "4", "11", "-3"], "11\n"),
+            (["largest", "-5", "-1", "-7"], "-1\n"),
+            (["join", "x", "y", "z"], "x-y-z\n"),
+            (["double", "1", "2", "3"], "2 4 6\n"),
+            (["swap", "1", "2", "3", "4"], "2 1 4 3\n"),
+            (["swap", "1", "2", "3"], "2 1 3\n"),
+        ],
+    )
+    def test_golden(self, argv, stdout):
+        GOLDEN.check(argv, stdout)
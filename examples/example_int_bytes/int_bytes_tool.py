@@ -0,0 +1,70 @@
+#!/usr/bin/env python3
+"""Int Bytes Example - int.to_bytes/from_bytes CLI.
+
+Encodes integers as fixed-width big- or little-endian byte strings
+(shown as hex) and decodes them back.
+
+Examples:
+    >>> encode(258, 4, "big")
+    '00000102'
+    >>> decode("00000102", "big")
+    258
+"""
+
+import argparse
+import sys
+
+
+def encode(n: int, length: int, order: str) -> str:
+    """Encode n into length bytes, rendered as lowercase hex.
+
+    >>> encode(1, 2, "little")
+    '0100'
+    >>> encode(0xDEADBEEF, 4, "big")
+    'deadbeef'
+    >>> encode(256, 1, "big")
+    Traceback (most recent call last):
+        ...
+    OverflowError: int too big to convert
+    """
+    return n.to_bytes(length, order).hex()
+
+
+def decode(hex_text: str, order: str) -> int:
+    """Decode a hex byte string back into an unsigned integer.
+
+    >>> decode("0100", "little")
+    1
+    >>> decode("ff", "big")
+    255
+    >>> decode("", "big")
+    0
+    """
+    return int.from_bytes(bytes.fromhex(hex_text), order)
+
+
+def main():
+    parser = argparse.ArgumentParser(description="Integer byte encoding tool")
+    subs = parser.add_subparsers(dest="cmd", required=True)
+
+    e = subs.add_parser("encode")
+    e.add_argument("n", type=int)
+    e.add_argument("--length", type=int, default=4)
+    e.add_argument("--order", choices=["big", "little"], default="big")
+    d = subs.add_parser("decode")
+    d.add_argument("hex")
+    d.add_argument("--order", choices=["big", "little"], default="big")
+
+    args = parser.parse_args()
+    if args.cmd == "encode":
+        try:
+            print(encode(args.n, args.length, args.order))
+        except OverflowError as err:
+            print(f"Error: {err}", file=sys.stderr)
+            sys.exit(1)
+    elif args.cmd == "decode":
+        print(decode(args.hex, args.order))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,48 @@
+"""Tests for int_bytes_tool.py"""
+
+from pathlib import Path
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "int_bytes_tool.py"
+
+run = GoldenCli(SCRIPT).run
+
+
+class TestBigEndian:
+    def test_encode_4_bytes(self):
+        stdout, _, code = run(["encode", "305419896"])
+        assert code == 0
+        assert stdout.strip() == "12345678"
+
+    def test_decode_4_bytes(self):
+        stdout, _, code = run(["decode", "12345678"])
+        assert code == 0
+        assert stdout.strip() == "305419896"
+
+    def test_round_trip(self):
+        encoded, _, _ = run(["encode", "4000000000"])
+        decoded, _, _ = run(["decode", encoded.strip()])
+        assert decoded.strip() == "4000000000"
+
+
+class TestLittleEndian:
+    def test_encode(self):
+        stdout, _, _ = run(["encode", "305419896", "--order", "little"])
+        assert stdout.strip() == "78563412"
+
+    def test_decode(self):
+        stdout, _, _ = run(["decode", "78563412", "--order", "little"])
+        assert stdout.strip() == "305419896"
+
+
+class TestLength:
+    def test_zero_padded(self):
+        stdout, _, _ = run(["encode", "5", "--length", "2"])
+        assert stdout.strip() == "0005"
+
+    def test_overflow(self):
+        stdout, stderr, code = run(["encode", "65536", "--length", "2"])
+        assert code == 1
+        assert stdout == ""
+        assert "int too big to convert" in stderr
@@ -1,5 +1,10 @@
 #!/usr/bin/env python3
-"""Int Float Example - Type conversion CLI."""
+"""Int Float Example - Type conversion CLI.
+
+Floats print with repr-style shortest round-trip digits. Signed zero
+keeps its sign (`-0.0`), and the special values print as `nan`, `inf`
+and `-inf`.
+"""
 
 import argparse
 
@@ -14,6 +19,10 @@ def main():
     f.add_argument("x", type=int)
     p = subs.add_parser("parse")
     p.add_argument("text")
+    sh = subs.add_parser("show")
+    sh.add_argument("text")
+    ng = subs.add_parser("negate")
+    ng.add_argument("x", type=float)
 
     args = parser.parse_args()
     if args.cmd == "toint":
@@ -22,6 +31,10 @@ def main():
         print(float(args.x))
     elif args.cmd == "parse":
         print(int(args.text))
+    elif args.cmd == "show":
+        print(float(args.text))
+    elif args.cmd == "negate":
+        print(-args.x)
 
 
 if __name__ == "__main__":
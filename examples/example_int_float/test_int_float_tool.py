@@ -0,0 +1,81 @@
+"""Tests for int_float_tool.py"""
+
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "int_float_tool.py"
+
+GOLDEN = GoldenCli(SCRIPT)
+run = GOLDEN.run
+
+
+class TestConvert:
+    def test_toint_truncates(self):
+        stdout, _, code = run(["toint", "-2.7"])
+        assert code == 0
+        assert stdout.strip() == "-2"
+
+    def test_tofloat(self):
+        stdout, _, _ = run(["tofloat", "3"])
+        assert stdout.strip() == "3.0"
+
+    def test_parse(self):
+        stdout, _, _ = run(["parse", "42"])
+        assert stdout.strip() == "42"
+
+
+class TestSpecialFloats:
+    @pytest.mark.parametrize(
+        "text,expected",
+        [
+            ("-0.0", "-0.0"),
+            ("0.0", "0.0"),
+            ("nan", "nan"),
+            ("NaN", "nan"),
+            ("-nan", "nan"),
+            ("inf", "inf"),
+            ("-inf", "-inf"),
+            ("Infinity", "inf"),
+            ("1e16", "1e+16"),
+            ("1e-5", "1e-05"),
+            ("0.1", "0.1"),
+        ],
+    )
+    def test_show(self, text, expected):
+        stdout, _, code = run(["show", "--", text])
+        assert code == 0
+        assert stdout.strip() == expected
+
+    def test_negate_zero(self):
+        stdout, _, _ = run(["negate", "0"])
+        assert stdout.strip() == "-0.0"
+
+    def test_negate_inf(self):
+        stdout, _, _ = run(["negate", "inf"])
+        assert stdout.strip() == "-inf"
+
+    def test_negate_nan(self):
+        stdout, _, _ = run(["negate", "nan"])
+        assert stdout.strip() == "nan"
+
+
+class TestGolden:
+    """Float printing must match Python for signed zero, nan and inf."""
+
+    @pytest.mark.parametrize(
+        "argv,stdout",
+        [
+            (["show", "-0.0"], "-0.0\n"),
+            (["show", "nan"], "nan\n"),
+            (["show", "inf"], "inf\n"),
+            (["show", "--", "-inf"], "-inf\n"),
+            (["show", "1e16"], "1e+16\n"),
+            (["negate", "0"], "-0.0\n"),
+            (["tofloat", "3"], "3.0\n"),
+        ],
+    )
+    def test_golden(self, argv, stdout):
+        GOLDEN.check(argv, stdout)
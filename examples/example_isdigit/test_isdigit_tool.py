@@ -0,0 +1,59 @@
+"""Tests for isdigit_tool.py"""
+
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "isdigit_tool.py"
+
+GOLDEN = GoldenCli(SCRIPT)
+run = GOLDEN.run
+
+
+class TestDigit:
+    def test_all_digits(self):
+        stdout, _, code = run(["digit", "123"])
+        assert code == 0
+        assert stdout.strip() == "true"
+
+    def test_trailing_letter(self):
+        stdout, _, _ = run(["digit", "12a"])
+        assert stdout.strip() == "false"
+
+    def test_empty_is_true(self):
+        stdout, _, _ = run(["digit", ""])
+        assert stdout.strip() == "true"
+
+
+class TestAlpha:
+    def test_mixed_case(self):
+        stdout, _, code = run(["alpha", "HeLLo"])
+        assert code == 0
+        assert stdout.strip() == "true"
+
+    def test_digit_in_word(self):
+        stdout, _, _ = run(["alpha", "abc1"])
+        assert stdout.strip() == "false"
+
+    def test_boundaries(self):
+        assert run(["alpha", "azAZ"])[0].strip() == "true"
+        assert run(["alpha", "a[z"])[0].strip() == "false"
+        assert run(["alpha", "a`z"])[0].strip() == "false"
+
+
+class TestGolden:
+    """result is a bool, so its truthiness test must not go through is_empty()"""
+
+    @pytest.mark.parametrize(
+        "argv,stdout",
+        [
+            (["digit", "123"], "true\n"),
+            (["digit", "12a"], "false\n"),
+            (["alpha", "HeLLo"], "true\n"),
+            (["alpha", "a[z"], "false\n"),
+        ],
+    )
+    def test_golden(self, argv, stdout):
+        GOLDEN.check(argv, stdout)
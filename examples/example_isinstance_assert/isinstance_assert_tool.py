@@ -0,0 +1,75 @@
+#!/usr/bin/env python3
+"""Isinstance Assert Example - Type-guard assertions CLI.
+
+Every argument already has its declared type by the time these asserts
+run, so each `assert isinstance(...)` always holds. A transpiler that
+knows the types can drop them entirely; output is the same with or
+without `python3 -O`.
+
+Examples:
+    >>> double(21)
+    42
+    >>> scale(1.5, 2.0)
+    3.0
+    >>> repeat("ab", 3)
+    'ababab'
+"""
+
+import argparse
+
+
+def double(n: int) -> int:
+    """Twice n.
+
+    >>> double(-4)
+    -8
+    """
+    assert isinstance(n, int)
+    return n * 2
+
+
+def scale(x: float, factor: float) -> float:
+    """x multiplied by factor.
+
+    >>> scale(2.0, 0.25)
+    0.5
+    """
+    assert isinstance(x, float), "x must be a float"
+    assert isinstance(factor, float), "factor must be a float"
+    return x * factor
+
+
+def repeat(text: str, n: int) -> str:
+    """text repeated n times.
+
+    >>> repeat("x", 0)
+    ''
+    """
+    assert isinstance(text, str) and isinstance(n, int)
+    return text * n
+
+
+def main():
+    parser = argparse.ArgumentParser(description="Type-guard assertion tool")
+    subs = parser.add_subparsers(dest="cmd", required=True)
+
+    d = subs.add_parser("double")
+    d.add_argument("n", type=int)
+    s = subs.add_parser("scale")
+    s.add_argument("x", type=float)
+    s.add_argument("--factor", type=float, default=2.0)
+    r = subs.add_parser("repeat")
+    r.add_argument("text")
+    r.add_argument("n", type=int)
+
+    args = parser.parse_args()
+    if args.cmd == "double":
+        print(double(args.n))
+    elif args.cmd == "scale":
+        print(scale(args.x, args.factor))
+    elif args.cmd == "repeat":
+        print(repeat(args.text, args.n))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,69 @@
+"""Tests for isinstance_assert_tool.py
+
+The isinstance asserts always pass, so a normal run and a `python3 -O`
+run must agree, and the Rust output must not test types at runtime.
+"""
+
+import subprocess
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "isinstance_assert_tool.py"
+
+GOLDEN = GoldenCli(SCRIPT)
+
+CASES = [
+    (["double", "21"], "42\n"),
+    (["double", "-4"], "-8\n"),
+    (["scale", "1.5"], "3.0\n"),
+    (["scale", "2", "--factor", "0.25"], "0.5\n"),
+    (["repeat", "ab", "3"], "ababab\n"),
+    (["repeat", "ab", "0"], "\n"),
+]
+
+
+def run(args, optimize=False):
+    """Run the CLI and return (stdout, stderr, returncode)."""
+    flags = ["-O"] if optimize else []
+    result = subprocess.run(
+        ["python3", *flags, str(SCRIPT)] + args,
+        capture_output=True,
+        text=True,
+    )
+    return result.stdout, result.stderr, result.returncode
+
+
+class TestAsserts:
+    @pytest.mark.parametrize("argv,expected", CASES)
+    def test_asserts_pass(self, argv, expected):
+        stdout, stderr, code = run(argv)
+        assert code == 0
+        assert stdout == expected
+        assert "AssertionError" not in stderr
+
+    @pytest.mark.parametrize("argv,expected", CASES)
+    def test_same_output_under_optimize(self, argv, expected):
+        stdout, _, code = run(argv, optimize=True)
+        assert code == 0
+        assert stdout == expected
+
+
+class TestBadInput:
+    def test_type_rejected_by_argparse(self):
+        _, stderr, code = run(["double", "abc"])
+        assert code == 2
+        assert "invalid int value" in stderr
+
+
+class TestGolden:
+    """isinstance asserts on known types must compile away."""
+
+    @pytest.mark.parametrize("argv,stdout", CASES)
+    def test_golden(self, argv, stdout):
+        GOLDEN.check(argv, stdout)
+
+    def test_no_runtime_type_check(self):
+        assert "isinstance" not in GOLDEN.rust_source_or_skip()
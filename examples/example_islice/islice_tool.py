@@ -0,0 +1,76 @@
+#!/usr/bin/env python3
+"""Islice Example - Lazy slicing of infinite iterators CLI.
+
+`islice` pulls only as many items as it needs, so it can slice the
+infinite `count()` and `cycle()` iterators without materializing them.
+
+Examples:
+    >>> first_n(5)
+    [0, 1, 2, 3, 4]
+    >>> window(10, 20, 3)
+    [10, 13, 16, 19]
+"""
+
+import argparse
+import sys
+from itertools import count, cycle, islice
+
+
+def first_n(n: int) -> list[int]:
+    """First n values of an infinite counter.
+
+    >>> first_n(0)
+    []
+    """
+    return list(islice(count(), n))
+
+
+def window(start: int, stop: int, step: int) -> list[int]:
+    """Items start..stop (exclusive) taking every step-th, from an infinite counter.
+
+    >>> window(0, 5, 1)
+    [0, 1, 2, 3, 4]
+    >>> window(3, 3, 1)
+    []
+    """
+    return list(islice(count(), start, stop, step))
+
+
+def cycled(letters: str, n: int) -> str:
+    """First n characters of letters repeated forever.
+
+    >>> cycled("ab", 5)
+    'ababa'
+    """
+    return "".join(islice(cycle(letters), n))
+
+
+def main():
+    parser = argparse.ArgumentParser(description="Lazy slice tool")
+    subs = parser.add_subparsers(dest="cmd", required=True)
+
+    f = subs.add_parser("first")
+    f.add_argument("n", type=int)
+    w = subs.add_parser("window")
+    w.add_argument("start", type=int)
+    w.add_argument("stop", type=int)
+    w.add_argument("step", type=int, nargs="?", default=1)
+    c = subs.add_parser("cycle")
+    c.add_argument("letters")
+    c.add_argument("n", type=int)
+
+    args = parser.parse_args()
+    try:
+        if args.cmd == "first":
+            print(" ".join(str(x) for x in first_n(args.n)))
+        elif args.cmd == "window":
+            print(" ".join(str(x) for x in window(args.start, args.stop, args.step)))
+        elif args.cmd == "cycle":
+            print(cycled(args.letters, args.n))
+    except ValueError as e:
+        print(f"Error: {e}", file=sys.stderr)
+        sys.exit(1)
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,62 @@
+"""Tests for islice_tool.py"""
+
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "islice_tool.py"
+
+run = GoldenCli(SCRIPT).run
+
+
+class TestFirst:
+    def test_first_five_of_infinite_counter(self):
+        stdout, _, code = run(["first", "5"])
+        assert code == 0
+        assert stdout.strip() == "0 1 2 3 4"
+
+    def test_large_n_stays_lazy(self):
+        stdout, _, code = run(["first", "100000"])
+        assert code == 0
+        assert stdout.split()[-1] == "99999"
+
+
+class TestWindow:
+    def test_start_stop(self):
+        stdout, _, _ = run(["window", "3", "7"])
+        assert stdout.strip() == "3 4 5 6"
+
+    def test_start_stop_step(self):
+        stdout, _, _ = run(["window", "0", "10", "3"])
+        assert stdout.strip() == "0 3 6 9"
+
+    def test_empty(self):
+        stdout, _, code = run(["window", "5", "2"])
+        assert code == 0
+        assert stdout == "\n"
+
+
+class TestCycle:
+    def test_cycle(self):
+        stdout, _, _ = run(["cycle", "xyz", "7"])
+        assert stdout.strip() == "xyzxyzx"
+
+
+class TestErrors:
+    @pytest.mark.parametrize(
+        "argv",
+        [
+            ["first", "-1"],
+            ["window", "0", "5", "0"],
+            ["window", "-1", "3"],
+            ["cycle", "ab", "-2"],
+        ],
+    )
+    def test_invalid_slice(self, argv):
+        stdout, stderr, code = run(argv)
+        assert code == 1
+        assert stdout == ""
+        assert stderr.startswith("Error: ")
+        assert "Traceback" not in stderr
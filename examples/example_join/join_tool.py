@@ -2,48 +2,72 @@
 """Join Example - String join operations CLI.
 
 Examples:
-    >>> join_underscore("a", "b", "c")
+    >>> join_underscore(["a", "b", "c"])
     'a_b_c'
-    >>> join_dash("x", "y", "z")
+    >>> join_dash(["x", "y", "z"])
     'x-y-z'
-    >>> join_dot("1", "2", "3")
+    >>> join_dot(["1", "2", "3"])
     '1.2.3'
+    >>> join_fmt("a", "b", "c")
+    'a_b_c'
 """
 
 import argparse
 
 
-def join_underscore(a: str, b: str, c: str) -> str:
-    """Join three strings with underscores.
+def join_underscore(parts: list[str]) -> str:
+    """Join strings with underscores.
 
-    >>> join_underscore("hello", "world", "test")
+    >>> join_underscore(["hello", "world", "test"])
     'hello_world_test'
-    >>> join_underscore("", "x", "")
+    >>> join_underscore(["", "x", ""])
     '_x_'
+    >>> join_underscore(["a", "b", "c", "d"])
+    'a_b_c_d'
     """
-    return a + "_" + b + "_" + c
+    return "_".join(parts)
 
 
-def join_dash(a: str, b: str, c: str) -> str:
-    """Join three strings with dashes.
+def join_dash(parts: list[str]) -> str:
+    """Join strings with dashes.
 
-    >>> join_dash("foo", "bar", "baz")
+    >>> join_dash(["foo", "bar", "baz"])
     'foo-bar-baz'
-    >>> join_dash("2025", "11", "29")
+    >>> join_dash(["2025", "11", "29"])
     '2025-11-29'
     """
-    return a + "-" + b + "-" + c
+    return "-".join(parts)
+
+
+def join_dot(parts: list[str]) -> str:
+    """Join strings with dots.
+
+    >>> join_dot(["192", "168", "1", "1"])
+    '192.168.1.1'
+    >>> join_dot(["solo"])
+    'solo'
+    """
+    return ".".join(parts)
+
+
+def join_fmt(a: str, b: str, c: str) -> str:
+    """Join exactly three strings with underscores via an f-string.
+
+    >>> join_fmt("", "x", "")
+    '_x_'
+    """
+    return f"{a}_{b}_{c}"
 
 
-def join_dot(a: str, b: str, c: str) -> str:
-    """Join three strings with dots.
+def join_with(sep: str, parts: list[str]) -> str:
+    """Join strings with a separator chosen at runtime.
 
-    >>> join_dot("192", "168", "1")
-    '192.168.1'
-    >>> join_dot("a", "b", "c")
-    'a.b.c'
+    >>> join_with(", ", ["a", "b"])
+    'a, b'
+    >>> join_with("", ["x", "y", "z"])
+    'xyz'
     """
-    return a + "." + b + "." + c
+    return sep.join(parts)
 
 
 def main():
@@ -51,25 +75,30 @@ def main():
     subs = parser.add_subparsers(dest="cmd", required=True)
 
     u = subs.add_parser("underscore")
-    u.add_argument("a")
-    u.add_argument("b")
-    u.add_argument("c")
+    u.add_argument("parts", nargs="+")
     d = subs.add_parser("dash")
-    d.add_argument("a")
-    d.add_argument("b")
-    d.add_argument("c")
+    d.add_argument("parts", nargs="+")
     dt = subs.add_parser("dot")
-    dt.add_argument("a")
-    dt.add_argument("b")
-    dt.add_argument("c")
+    dt.add_argument("parts", nargs="+")
+    f = subs.add_parser("fmt")
+    f.add_argument("a")
+    f.add_argument("b")
+    f.add_argument("c")
+    c = subs.add_parser("custom")
+    c.add_argument("parts", nargs="+")
+    c.add_argument("--sep", default=",")
 
     args = parser.parse_args()
     if args.cmd == "underscore":
-        print(join_underscore(args.a, args.b, args.c))
+        print(join_underscore(args.parts))
     elif args.cmd == "dash":
-        print(join_dash(args.a, args.b, args.c))
+        print(join_dash(args.parts))
     elif args.cmd == "dot":
-        print(join_dot(args.a, args.b, args.c))
+        print(join_dot(args.parts))
+    elif args.cmd == "fmt":
+        print(join_fmt(args.a, args.b, args.c))
+    elif args.cmd == "custom":
+        print(join_with(args.sep, args.parts))
 
 
 if __name__ == "__main__":
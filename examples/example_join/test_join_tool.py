@@ -0,0 +1,120 @@
+"""Tests for join_tool.py"""
+
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "join_tool.py"
+SPLIT = GoldenCli(Path(__file__).parent.parent / "example_split" / "split_tool.py")
+
+GOLDEN = GoldenCli(SCRIPT)
+run = GOLDEN.run
+
+
+class TestJoin:
+    def test_underscore(self):
+        stdout, _, code = run(["underscore", "a", "b", "c"])
+        assert code == 0
+        assert stdout == "a_b_c\n"
+
+    def test_dash(self):
+        stdout, _, _ = run(["dash", "2025", "11", "29"])
+        assert stdout == "2025-11-29\n"
+
+    def test_dot(self):
+        stdout, _, _ = run(["dot", "192", "168", "1"])
+        assert stdout == "192.168.1\n"
+
+    def test_empty_parts(self):
+        stdout, _, _ = run(["underscore", "", "x", ""])
+        assert stdout == "_x_\n"
+
+    def test_four_parts(self):
+        stdout, _, _ = run(["underscore", "a", "b", "c", "d"])
+        assert stdout == "a_b_c_d\n"
+
+    def test_single_part(self):
+        stdout, _, _ = run(["dot", "solo"])
+        assert stdout == "solo\n"
+
+    def test_many_parts(self):
+        parts = [str(i) for i in range(20)]
+        stdout, _, _ = run(["dash"] + parts)
+        assert stdout == "-".join(parts) + "\n"
+
+    def test_no_parts(self):
+        _, _, code = run(["underscore"])
+        assert code == 2
+
+
+class TestFmt:
+    def test_three_parts(self):
+        stdout, _, code = run(["fmt", "a", "b", "c"])
+        assert code == 0
+        assert stdout == "a_b_c\n"
+
+    def test_empty_parts(self):
+        stdout, _, _ = run(["fmt", "", "x", ""])
+        assert stdout == "_x_\n"
+
+    def test_matches_join(self):
+        via_fmt, _, _ = run(["fmt", "foo", "bar", "baz"])
+        via_join, _, _ = run(["underscore", "foo", "bar", "baz"])
+        assert via_fmt == via_join
+
+    def test_wrong_count(self):
+        _, _, code = run(["fmt", "a", "b"])
+        assert code == 2
+
+
+class TestCustomSep:
+    def test_default_comma(self):
+        stdout, _, code = run(["custom", "a", "b", "c"])
+        assert code == 0
+        assert stdout == "a,b,c\n"
+
+    def test_multichar_sep(self):
+        stdout, _, _ = run(["custom", "a", "b", "c", "--sep", " :: "])
+        assert stdout == "a :: b :: c\n"
+
+    def test_empty_sep(self):
+        stdout, _, _ = run(["custom", "a", "b", "--sep", ""])
+        assert stdout == "ab\n"
+
+    def test_multibyte_sep(self):
+        stdout, _, _ = run(["custom", "x", "y", "--sep", "→"])
+        assert stdout == "x→y\n"
+
+
+class TestRoundTrip:
+    @pytest.mark.parametrize("cmd", ["underscore", "dash", "dot"])
+    def test_split_undoes_join(self, cmd):
+        joined, _, _ = run([cmd, "foo", "bar", "baz"])
+        stdout, _, code = SPLIT.run([cmd, joined.strip()])
+        assert code == 0
+        assert stdout == "foo bar baz\n"
+
+
+class TestGolden:
+    """sep.join(parts) must lower to Vec::join, the fmt f-string to one format!."""
+
+    @pytest.mark.parametrize(
+        "argv,stdout",
+        [
+            (["underscore", "a", "b", "c"], "a_b_c\n"),
+            (["dash", "foo", "bar", "baz"], "foo-bar-baz\n"),
+            (["dot", "1", "2", "3"], "1.2.3\n"),
+            (["underscore", "", "x", ""], "_x_\n"),
+            (["underscore", "a", "b", "c", "d"], "a_b_c_d\n"),
+            (["dot", "solo"], "solo\n"),
+            (["fmt", "a", "b", "c"], "a_b_c\n"),
+            (["fmt", "", "x", ""], "_x_\n"),
+            (["custom", "a", "b", "c"], "a,b,c\n"),
+            (["custom", "a", "b", "--sep", " :: "], "a :: b\n"),
+            (["custom", "a", "b", "--sep", ""], "ab\n"),
+        ],
+    )
+    def test_golden(self, argv, stdout):
+        GOLDEN.check(argv, stdout)
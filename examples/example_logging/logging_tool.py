@@ -0,0 +1,75 @@
+#!/usr/bin/env python3
+"""Logging Example - Leveled logging CLI.
+
+`logging.basicConfig(level=...)` decides which of the logger's
+debug/info/error records reach stderr; results still go to stdout.
+
+Examples:
+    >>> checksum("abc")
+    294
+    >>> level_value("debug")
+    10
+"""
+
+import argparse
+import logging
+
+logger = logging.getLogger("logging_tool")
+
+
+def level_value(name: str) -> int:
+    """Map a level name to its numeric logging level.
+
+    >>> level_value("info")
+    20
+    >>> level_value("ERROR")
+    40
+    """
+    return getattr(logging, name.upper())
+
+
+def checksum(text: str) -> int:
+    """Sum of character codes, logging progress along the way.
+
+    >>> checksum("")
+    0
+    >>> checksum("A")
+    65
+    """
+    logger.debug("hashing %d characters", len(text))
+    total = 0
+    for c in text:
+        total = total + ord(c)
+    logger.info("checksum computed")
+    return total
+
+
+def main():
+    parser = argparse.ArgumentParser(description="Logging tool")
+    parser.add_argument(
+        "--level", default="warning", choices=["debug", "info", "warning", "error"]
+    )
+    subs = parser.add_subparsers(dest="cmd", required=True)
+
+    c = subs.add_parser("checksum")
+    c.add_argument("text")
+    d = subs.add_parser("divide")
+    d.add_argument("a", type=int)
+    d.add_argument("b", type=int)
+
+    args = parser.parse_args()
+    logging.basicConfig(level=level_value(args.level), format="%(levelname)s: %(message)s")
+
+    if args.cmd == "checksum":
+        print(checksum(args.text))
+    elif args.cmd == "divide":
+        if args.b == 0:
+            logger.error("division by zero")
+            print(0)
+        else:
+            logger.info("dividing %d by %d", args.a, args.b)
+            print(args.a // args.b)
+
+
+if __name__ == "__main__":
+    main()
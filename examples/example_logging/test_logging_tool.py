@@ -0,0 +1,48 @@
+"""Tests for logging_tool.py"""
+
+from pathlib import Path
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "logging_tool.py"
+
+run = GoldenCli(SCRIPT).run
+
+
+class TestLevels:
+    def test_default_level_hides_info(self):
+        stdout, stderr, code = run(["checksum", "abc"])
+        assert code == 0
+        assert stdout.strip() == "294"
+        assert stderr == ""
+
+    def test_info_level_emits_info(self):
+        stdout, stderr, code = run(["--level", "info", "checksum", "abc"])
+        assert code == 0
+        assert stdout.strip() == "294"
+        assert "INFO: checksum computed" in stderr
+        assert "DEBUG" not in stderr
+
+    def test_debug_level_emits_debug_and_info(self):
+        _, stderr, code = run(["--level", "debug", "checksum", "abc"])
+        assert code == 0
+        assert "DEBUG: hashing 3 characters" in stderr
+        assert "INFO: checksum computed" in stderr
+
+    def test_error_level_hides_info(self):
+        _, stderr, code = run(["--level", "error", "divide", "7", "2"])
+        assert code == 0
+        assert "INFO" not in stderr
+
+
+class TestError:
+    def test_error_always_emitted(self):
+        stdout, stderr, code = run(["divide", "7", "0"])
+        assert code == 0
+        assert stdout.strip() == "0"
+        assert "ERROR: division by zero" in stderr
+
+    def test_info_with_arguments(self):
+        stdout, stderr, _ = run(["--level", "info", "divide", "7", "2"])
+        assert stdout.strip() == "3"
+        assert "INFO: dividing 7 by 2" in stderr
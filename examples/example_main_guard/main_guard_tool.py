@@ -0,0 +1,44 @@
+#!/usr/bin/env python3
+"""Main Guard Example - Module init vs __main__ entry point CLI.
+
+Top-level statements outside the guard (the SQUARES table) run on import;
+only the body of `if __name__ == "__main__":` runs as the program entry.
+
+Examples:
+    >>> SQUARES[:4]
+    [0, 1, 4, 9]
+    >>> is_square(49)
+    True
+"""
+
+import argparse
+
+LIMIT = 20
+
+# Module initialization: computed once, whether imported or run.
+SQUARES = []
+for _n in range(LIMIT):
+    SQUARES.append(_n * _n)
+
+
+def is_square(n: int) -> bool:
+    """Whether n is a perfect square below LIMIT squared.
+
+    >>> is_square(0)
+    True
+    >>> is_square(50)
+    False
+    """
+    return n in SQUARES
+
+
+def main():
+    parser = argparse.ArgumentParser(description="Main guard tool")
+    parser.add_argument("n", type=int)
+    args = parser.parse_args()
+    print(f"{args.n} square={is_square(args.n)}")
+
+
+if __name__ == "__main__":
+    print("entry: main")
+    main()
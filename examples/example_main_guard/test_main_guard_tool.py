@@ -0,0 +1,49 @@
+"""Tests for main_guard_tool.py"""
+
+import subprocess
+from pathlib import Path
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "main_guard_tool.py"
+
+run = GoldenCli(SCRIPT).run
+
+
+def import_and_print(expr):
+    """Import the module without running it as __main__ and print expr."""
+    code = f"import main_guard_tool as m; print({expr})"
+    result = subprocess.run(
+        ["python3", "-c", code],
+        capture_output=True,
+        text=True,
+        cwd=SCRIPT.parent,
+    )
+    return result.stdout, result.stderr, result.returncode
+
+
+class TestRunAsMain:
+    def test_guarded_code_runs(self):
+        stdout, _, code = run(["16"])
+        assert code == 0
+        assert stdout.splitlines() == ["entry: main", "16 square=True"]
+
+    def test_not_square(self):
+        stdout, _, _ = run(["15"])
+        assert stdout.splitlines()[-1] == "15 square=False"
+
+
+class TestImport:
+    def test_guarded_code_skipped(self):
+        stdout, _, code = import_and_print("'imported'")
+        assert code == 0
+        assert stdout.strip() == "imported"
+        assert "entry: main" not in stdout
+
+    def test_module_init_runs(self):
+        stdout, _, _ = import_and_print("len(m.SQUARES)")
+        assert stdout.strip() == "20"
+
+    def test_helpers_available(self):
+        stdout, _, _ = import_and_print("m.is_square(361)")
+        assert stdout.strip() == "True"
@@ -0,0 +1,62 @@
+#!/usr/bin/env python3
+"""Multiline Example - Triple-quoted string literals CLI.
+
+The banner keeps its embedded newlines, quotes and backslashes exactly
+as written; the template is filled in with `str.format`.
+
+Examples:
+    >>> BANNER.count("\\n")
+    4
+    >>> render_card("Ada", 36).splitlines()[1]
+    '| name: Ada'
+"""
+
+import argparse
+
+BANNER = """+----------------------+
+|  "quoted" \\ tool v1  |
+|  it's multi-line     |
++----------------------+
+"""
+
+CARD = """+--
+| name: {name}
+| age:  {age}
++--"""
+
+
+def render_card(name: str, age: int) -> str:
+    """Fill the multi-line card template.
+
+    >>> print(render_card("Bob", 7))
+    +--
+    | name: Bob
+    | age:  7
+    +--
+    """
+    return CARD.format(name=name, age=age)
+
+
+def main():
+    parser = argparse.ArgumentParser(
+        description="""Multi-line literal tool.
+
+Prints banners and templates built from triple-quoted strings.""",
+        formatter_class=argparse.RawDescriptionHelpFormatter,
+    )
+    subs = parser.add_subparsers(dest="cmd", required=True)
+
+    subs.add_parser("banner")
+    c = subs.add_parser("card")
+    c.add_argument("name")
+    c.add_argument("age", type=int)
+
+    args = parser.parse_args()
+    if args.cmd == "banner":
+        print(BANNER, end="")
+    elif args.cmd == "card":
+        print(render_card(args.name, args.age))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,39 @@
+"""Tests for multiline_tool.py"""
+
+from pathlib import Path
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "multiline_tool.py"
+
+run = GoldenCli(SCRIPT).run
+
+
+class TestBanner:
+    def test_banner_exact(self):
+        stdout, _, code = run(["banner"])
+        assert code == 0
+        assert stdout == (
+            "+----------------------+\n"
+            '|  "quoted" \\ tool v1  |\n'
+            "|  it's multi-line     |\n"
+            "+----------------------+\n"
+        )
+
+    def test_banner_line_count(self):
+        stdout, _, _ = run(["banner"])
+        assert len(stdout.splitlines()) == 4
+
+
+class TestCard:
+    def test_card(self):
+        stdout, _, code = run(["card", "Ada", "36"])
+        assert code == 0
+        assert stdout == "+--\n| name: Ada\n| age:  36\n+--\n"
+
+
+class TestHelp:
+    def test_description_keeps_newlines(self):
+        stdout, _, code = run(["--help"])
+        assert code == 0
+        assert "Multi-line literal tool.\n\nPrints banners" in stdout
@@ -0,0 +1,81 @@
+#!/usr/bin/env python3
+"""Negative Index Example - Writing through negative indices CLI.
+
+`xs[-k] = v` writes to position `len(xs) - k`. An index below
+`-len(xs)` raises IndexError, just like reading it would.
+
+Examples:
+    >>> set_last([1, 2, 3], 9)
+    [1, 2, 9]
+    >>> set_from_end([1, 2, 3], 3, 0)
+    [0, 2, 3]
+"""
+
+import argparse
+import sys
+
+
+def set_last(xs: list[int], value: int) -> list[int]:
+    """Replace the last element.
+
+    >>> set_last([5], 1)
+    [1]
+    """
+    xs[-1] = value
+    return xs
+
+
+def set_from_end(xs: list[int], k: int, value: int) -> list[int]:
+    """Replace the k-th element from the end (k >= 1).
+
+    >>> set_from_end([1, 2], 3, 0)
+    Traceback (most recent call last):
+        ...
+    IndexError: list assignment index out of range
+    """
+    xs[-k] = value
+    return xs
+
+
+def swap_ends(xs: list[int]) -> list[int]:
+    """Swap the first and last elements.
+
+    >>> swap_ends([1, 2, 3])
+    [3, 2, 1]
+    """
+    first = xs[0]
+    xs[0] = xs[-1]
+    xs[-1] = first
+    return xs
+
+
+def main():
+    parser = argparse.ArgumentParser(description="Negative index assignment tool")
+    subs = parser.add_subparsers(dest="cmd", required=True)
+
+    la = subs.add_parser("last")
+    la.add_argument("value", type=int)
+    la.add_argument("values", type=int, nargs="+")
+    fe = subs.add_parser("from-end")
+    fe.add_argument("k", type=int)
+    fe.add_argument("value", type=int)
+    fe.add_argument("values", type=int, nargs="+")
+    sw = subs.add_parser("swap")
+    sw.add_argument("values", type=int, nargs="+")
+
+    args = parser.parse_args()
+    try:
+        if args.cmd == "last":
+            result = set_last(args.values, args.value)
+        elif args.cmd == "from-end":
+            result = set_from_end(args.values, args.k, args.value)
+        else:
+            result = swap_ends(args.values)
+    except IndexError as e:
+        print(f"Error: {e}", file=sys.stderr)
+        sys.exit(1)
+    print(" ".join(str(x) for x in result))
+
+
+if __name__ == "__main__":
+    main()
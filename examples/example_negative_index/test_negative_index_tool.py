@@ -0,0 +1,42 @@
+"""Tests for negative_index_tool.py"""
+
+from pathlib import Path
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "negative_index_tool.py"
+
+run = GoldenCli(SCRIPT).run
+
+
+class TestLast:
+    def test_set_last(self):
+        stdout, _, code = run(["last", "9", "1", "2", "3"])
+        assert code == 0
+        assert stdout.strip() == "1 2 9"
+
+    def test_single_element(self):
+        stdout, _, _ = run(["last", "7", "1"])
+        assert stdout.strip() == "7"
+
+
+class TestFromEnd:
+    def test_second_from_end(self):
+        stdout, _, _ = run(["from-end", "2", "0", "1", "2", "3"])
+        assert stdout.strip() == "1 0 3"
+
+    def test_whole_length_is_first(self):
+        stdout, _, _ = run(["from-end", "3", "0", "1", "2", "3"])
+        assert stdout.strip() == "0 2 3"
+
+    def test_out_of_range(self):
+        stdout, stderr, code = run(["from-end", "4", "0", "1", "2", "3"])
+        assert code == 1
+        assert stdout == ""
+        assert "Error: list assignment index out of range" in stderr
+
+
+class TestSwap:
+    def test_swap(self):
+        stdout, _, _ = run(["swap", "1", "2", "3", "4"])
+        assert stdout.strip() == "4 2 3 1"
@@ -0,0 +1,70 @@
+#!/usr/bin/env python3
+"""Nl Example - Line numbering CLI.
+
+Reads stdin and prefixes lines with a number and a tab, in the style of
+`nl`. Numbering combines `splitlines`, `enumerate` with a start value
+and an f-string.
+
+Examples:
+    >>> number_all("a\\nb")
+    ['1\\ta', '2\\tb']
+    >>> number_nonblank("a\\n\\nb")
+    ['1\\ta', '', '2\\tb']
+"""
+
+import argparse
+import sys
+
+
+def number_all(text: str, start: int = 1) -> list[str]:
+    """Number every line, blank or not.
+
+    >>> number_all("x\\n\\ny", start=10)
+    ['10\\tx', '11\\t', '12\\ty']
+    >>> number_all("")
+    []
+    """
+    out = []
+    for i, line in enumerate(text.splitlines(), start):
+        out.append(f"{i}\t{line}")
+    return out
+
+
+def number_nonblank(text: str, start: int = 1) -> list[str]:
+    """Number only lines with visible text; blank lines stay empty.
+
+    >>> number_nonblank("  \\nz")
+    ['', '1\\tz']
+    """
+    out = []
+    n = start
+    for line in text.splitlines():
+        if line.strip():
+            out.append(f"{n}\t{line}")
+            n = n + 1
+        else:
+            out.append("")
+    return out
+
+
+def main():
+    parser = argparse.ArgumentParser(description="Line numbering tool")
+    subs = parser.add_subparsers(dest="cmd", required=True)
+
+    a = subs.add_parser("all")
+    a.add_argument("--start", type=int, default=1)
+    nb = subs.add_parser("nonblank")
+    nb.add_argument("--start", type=int, default=1)
+
+    args = parser.parse_args()
+    text = sys.stdin.read()
+    if args.cmd == "all":
+        lines = number_all(text, args.start)
+    else:
+        lines = number_nonblank(text, args.start)
+    for line in lines:
+        print(line)
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,66 @@
+"""Tests for nl_tool.py"""
+
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "nl_tool.py"
+
+GOLDEN = GoldenCli(SCRIPT)
+run = GOLDEN.run
+
+
+class TestAll:
+    def test_numbers_each_line(self):
+        stdout, _, code = run(["all"], "alpha\nbeta\ngamma\n")
+        assert code == 0
+        assert stdout == "1\talpha\n2\tbeta\n3\tgamma\n"
+
+    def test_blank_lines_numbered(self):
+        stdout, _, _ = run(["all"], "a\n\nb\n")
+        assert stdout == "1\ta\n2\t\n3\tb\n"
+
+    def test_no_trailing_newline(self):
+        stdout, _, _ = run(["all"], "a\nb")
+        assert stdout == "1\ta\n2\tb\n"
+
+    def test_start(self):
+        stdout, _, _ = run(["all", "--start", "0"], "a\nb\n")
+        assert stdout == "0\ta\n1\tb\n"
+
+    def test_empty_input(self):
+        stdout, _, code = run(["all"], "")
+        assert code == 0
+        assert stdout == ""
+
+    def test_tenth_line(self):
+        stdout, _, _ = run(["all"], "".join(f"l{i}\n" for i in range(10)))
+        assert stdout.splitlines()[-1] == "10\tl9"
+
+
+class TestNonblank:
+    def test_skips_blank(self):
+        stdout, _, _ = run(["nonblank"], "a\n\n  \nb\n")
+        assert stdout == "1\ta\n\n\n2\tb\n"
+
+    def test_start(self):
+        stdout, _, _ = run(["nonblank", "--start", "5"], "x\n\ny\n")
+        assert stdout == "5\tx\n\n6\ty\n"
+
+
+class TestGolden:
+    """splitlines + enumerate(start) + f-string must number lines end to end."""
+
+    @pytest.mark.parametrize(
+        "argv,stdin,stdout",
+        [
+            (["all"], "alpha\nbeta\n", "1\talpha\n2\tbeta\n"),
+            (["all", "--start", "0"], "a\n\nb", "0\ta\n1\t\n2\tb\n"),
+            (["nonblank"], "a\n\nb\n", "1\ta\n\n2\tb\n"),
+            (["all"], "", ""),
+        ],
+    )
+    def test_golden(self, argv, stdin, stdout):
+        GOLDEN.check(argv, stdout, stdin=stdin)
@@ -0,0 +1,49 @@
+"""Tests for numpy_add_tool.py"""
+
+import re
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+pytest.importorskip("numpy")
+
+SCRIPT = Path(__file__).parent / "numpy_add_tool.py"
+
+GOLDEN = GoldenCli(SCRIPT)
+run = GOLDEN.run
+
+
+class TestAdd:
+    def test_add2(self):
+        stdout, _, code = run(["add2", "1", "2", "3", "4"])
+        assert code == 0
+        assert stdout.strip() == "4.0 6.0"
+
+    def test_add3(self):
+        stdout, _, _ = run(["add3", "1", "2", "3", "0.5", "0.5", "0.5"])
+        assert stdout.strip() == "1.5 2.5 3.5"
+
+
+@pytest.fixture(scope="module")
+def rust_source():
+    """Generated Rust for the example, transpiled once per module."""
+    return GOLDEN.rust_source_or_skip()
+
+
+class TestGeneratedImports:
+    """import numpy as np must lower to a trueno import with no np left behind."""
+
+    def test_uses_trueno(self, rust_source):
+        assert "use trueno::Vector;" in rust_source
+
+    def test_no_np_reference(self, rust_source):
+        assert re.search(r"\bnp(::|\.)", rust_source) is None
+
+
+class TestGolden:
+    """The transpiled binary must match NumPy's element-wise add."""
+
+    def test_golden_add2(self):
+        GOLDEN.check(["add2", "1", "2", "3", "4"], stdout="4.0 6.0\n")
@@ -0,0 +1,47 @@
+"""Tests for numpy_cosine_tool.py"""
+
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+pytest.importorskip("numpy")
+
+SCRIPT = Path(__file__).parent / "numpy_cosine_tool.py"
+
+GOLDEN = GoldenCli(SCRIPT)
+run = GOLDEN.run
+
+
+class TestCosine:
+    def test_parallel(self):
+        stdout, _, code = run(["cosine2", "1", "0", "1", "0"])
+        assert code == 0
+        assert stdout.strip() == "1.0"
+
+    def test_orthogonal(self):
+        stdout, _, _ = run(["cosine2", "1", "0", "0", "1"])
+        assert stdout.strip() == "0.0"
+
+    def test_opposite(self):
+        stdout, _, _ = run(["cosine3", "1", "2", "3", "-1", "-2", "-3"])
+        assert stdout.strip() == "-1.0"
+
+    def test_zero_vector_takes_else_branch(self):
+        stdout, _, _ = run(["cosine2", "0", "0", "1", "0"])
+        assert stdout.strip() == "0"
+
+
+class TestGolden:
+    """The norm guards compare f64 values and both branches must unify to f64"""
+
+    @pytest.mark.parametrize(
+        "argv,stdout",
+        [
+            (["cosine2", "1", "0", "1", "0"], "1.0\n"),
+            (["cosine4", "1", "1", "0", "0", "1", "0", "0", "0"], "0.707\n"),
+        ],
+    )
+    def test_golden(self, argv, stdout):
+        GOLDEN.check(argv, stdout)
@@ -0,0 +1,48 @@
+"""Tests for numpy_norm_tool.py"""
+
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+pytest.importorskip("numpy")
+
+SCRIPT = Path(__file__).parent / "numpy_norm_tool.py"
+
+GOLDEN = GoldenCli(SCRIPT)
+run = GOLDEN.run
+
+
+class TestNorms:
+    def test_l2(self):
+        stdout, _, code = run(["l2", "3", "4"])
+        assert code == 0
+        assert stdout.strip() == "5.0"
+
+    def test_l1_sums_absolute_values(self):
+        stdout, _, _ = run(["l1", "1", "-2", "3"])
+        assert stdout.strip() == "6.0"
+
+    def test_linf_is_max_absolute(self):
+        stdout, _, _ = run(["linf", "1", "-2", "3"])
+        assert stdout.strip() == "3.0"
+
+    def test_linf_negative_max(self):
+        stdout, _, _ = run(["linf", "1", "-5", "3"])
+        assert stdout.strip() == "5.0"
+
+
+class TestGolden:
+    """Each arm must keep its ord; l1, l2 and linf differ on the same input"""
+
+    @pytest.mark.parametrize(
+        "argv,stdout",
+        [
+            (["l2", "3", "4"], "5.0\n"),
+            (["l1", "1", "-2", "3"], "6.0\n"),
+            (["linf", "1", "-2", "3"], "3.0\n"),
+        ],
+    )
+    def test_golden(self, argv, stdout):
+        GOLDEN.check(argv, stdout)
@@ -0,0 +1,52 @@
+#!/usr/bin/env python3
+"""Parse Argv Example - parse_args on an explicit list CLI.
+
+`run(argv)` parses the list it is given instead of `sys.argv`, so the
+tool can be embedded and tested in-process; `main()` passes None to fall
+back to the real command line.
+
+Examples:
+    >>> run(["--width", "5", "7"])
+    '    7'
+    >>> run(["--fill", "0", "--width", "3", "7"])
+    '007'
+"""
+
+import argparse
+
+
+def build_parser() -> argparse.ArgumentParser:
+    """Build the argument parser shared by run() and defaults()."""
+    parser = argparse.ArgumentParser(prog="parse_argv_tool", description="Padding tool")
+    parser.add_argument("value", type=int)
+    parser.add_argument("--width", type=int, default=1)
+    parser.add_argument("--fill", default=" ")
+    return parser
+
+
+def run(argv: list[str] | None) -> str:
+    """Parse argv (or sys.argv when None) and return the padded value.
+
+    >>> run(["42"])
+    '42'
+    """
+    args = build_parser().parse_args(argv)
+    return str(args.value).rjust(args.width, args.fill)
+
+
+def defaults() -> str:
+    """Parse a fixed argv to report the effective defaults.
+
+    >>> defaults()
+    'width=1 fill=" "'
+    """
+    args = build_parser().parse_args(["0"])
+    return f'width={args.width} fill="{args.fill}"'
+
+
+def main():
+    print(run(None))
+
+
+if __name__ == "__main__":
+    main()
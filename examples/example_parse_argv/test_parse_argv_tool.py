@@ -0,0 +1,40 @@
+"""Tests for parse_argv_tool.py"""
+
+import subprocess
+from pathlib import Path
+
+import pytest
+from parse_argv_tool import build_parser, defaults, run
+
+SCRIPT = Path(__file__).parent / "parse_argv_tool.py"
+
+
+class TestExplicitArgv:
+    def test_parse_list(self):
+        args = build_parser().parse_args(["--width", "4", "12"])
+        assert args.value == 12
+        assert args.width == 4
+        assert args.fill == " "
+
+    def test_run_list(self):
+        assert run(["--width", "4", "12"]) == "  12"
+
+    def test_fill(self):
+        assert run(["--fill", "*", "--width", "5", "-3"]) == "***-3"
+
+    def test_fixed_argv_defaults(self):
+        assert defaults() == 'width=1 fill=" "'
+
+    def test_invalid_list_exits(self):
+        with pytest.raises(SystemExit) as exc:
+            run(["--width", "x", "1"])
+        assert exc.value.code == 2
+
+
+class TestCommandLine:
+    def test_matches_in_process(self):
+        result = subprocess.run(
+            ["python3", str(SCRIPT), "--width", "4", "12"], capture_output=True, text=True
+        )
+        assert result.returncode == 0
+        assert result.stdout == run(["--width", "4", "12"]) + "\n"
@@ -0,0 +1,80 @@
+#!/usr/bin/env python3
+"""Pathlib Type Example - Path-typed argparse arguments CLI.
+
+Arguments declared with `type=pathlib.Path` arrive as `Path` objects
+rather than strings, so `.name`, `.stem`, `.suffix`, `/` and `.exists()`
+apply directly.
+
+Examples:
+    >>> stem_of(pathlib.Path("dir/report.tar.gz"))
+    'report.tar'
+    >>> suffix_of(pathlib.Path("notes.txt"))
+    '.txt'
+"""
+
+import argparse
+import pathlib
+
+
+def stem_of(p: pathlib.Path) -> str:
+    """Final component without its last suffix.
+
+    >>> stem_of(pathlib.Path("a/b/main.rs"))
+    'main'
+    >>> stem_of(pathlib.Path("Makefile"))
+    'Makefile'
+    """
+    return p.stem
+
+
+def suffix_of(p: pathlib.Path) -> str:
+    """Last suffix including the dot, empty if none.
+
+    >>> suffix_of(pathlib.Path("archive.tar.gz"))
+    '.gz'
+    >>> suffix_of(pathlib.Path("README"))
+    ''
+    """
+    return p.suffix
+
+
+def join_child(base: pathlib.Path, child: str) -> pathlib.Path:
+    """Join a child component onto a base path with `/`.
+
+    >>> str(join_child(pathlib.Path("src"), "lib.rs"))
+    'src/lib.rs'
+    """
+    return base / child
+
+
+def main():
+    parser = argparse.ArgumentParser(description="Path type tool")
+    subs = parser.add_subparsers(dest="cmd", required=True)
+
+    n = subs.add_parser("name")
+    n.add_argument("path", type=pathlib.Path)
+    s = subs.add_parser("stem")
+    s.add_argument("path", type=pathlib.Path)
+    x = subs.add_parser("suffix")
+    x.add_argument("path", type=pathlib.Path)
+    j = subs.add_parser("join")
+    j.add_argument("base", type=pathlib.Path)
+    j.add_argument("child")
+    e = subs.add_parser("exists")
+    e.add_argument("path", type=pathlib.Path)
+
+    args = parser.parse_args()
+    if args.cmd == "name":
+        print(args.path.name)
+    elif args.cmd == "stem":
+        print(stem_of(args.path))
+    elif args.cmd == "suffix":
+        print(suffix_of(args.path))
+    elif args.cmd == "join":
+        print(join_child(args.base, args.child))
+    elif args.cmd == "exists":
+        print(args.path.exists())
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,70 @@
+"""Tests for path_type_tool.py"""
+
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "path_type_tool.py"
+
+GOLDEN = GoldenCli(SCRIPT)
+run = GOLDEN.run
+
+
+class TestComponents:
+    def test_stem(self):
+        stdout, _, code = run(["stem", "/tmp/data/report.csv"])
+        assert code == 0
+        assert stdout.strip() == "report"
+
+    def test_stem_multiple_suffixes(self):
+        stdout, _, _ = run(["stem", "backup.tar.gz"])
+        assert stdout.strip() == "backup.tar"
+
+    def test_name(self):
+        stdout, _, _ = run(["name", "a/b/c.txt"])
+        assert stdout.strip() == "c.txt"
+
+    def test_suffix(self):
+        stdout, _, _ = run(["suffix", "a/b/c.txt"])
+        assert stdout.strip() == ".txt"
+
+    def test_suffix_missing(self):
+        stdout, _, code = run(["suffix", "Makefile"])
+        assert code == 0
+        assert stdout == "\n"
+
+
+class TestJoinExists:
+    def test_join(self):
+        stdout, _, _ = run(["join", "src", "main.rs"])
+        assert stdout.strip() == "src/main.rs"
+
+    def test_exists_true(self, tmp_path):
+        f = tmp_path / "present.txt"
+        f.write_text("x")
+        stdout, _, _ = run(["exists", str(f)])
+        assert stdout.strip() == "True"
+
+    def test_exists_false(self, tmp_path):
+        stdout, _, _ = run(["exists", str(tmp_path / "missing.txt")])
+        assert stdout.strip() == "False"
+
+
+class TestGolden:
+    """Path arguments must lower to PathBuf with Python's component rules."""
+
+    @pytest.mark.parametrize(
+        "argv,stdout",
+        [
+            (["stem", "/tmp/data/report.csv"], "report\n"),
+            (["stem", "backup.tar.gz"], "backup.tar\n"),
+            (["name", "a/b/c.txt"], "c.txt\n"),
+            (["suffix", "a/b/c.txt"], ".txt\n"),
+            (["suffix", "Makefile"], "\n"),
+            (["join", "src", "main.rs"], "src/main.rs\n"),
+        ],
+    )
+    def test_golden(self, argv, stdout):
+        GOLDEN.check(argv, stdout)
@@ -8,6 +8,8 @@ Examples:
     27
     >>> power(2, 10)
     1024
+    >>> wrap32(2**32 + 5)
+    5
 """
 
 import argparse
@@ -57,6 +59,19 @@ def power(base: int, exp: int) -> int:
     return result
 
 
+def wrap32(x: int) -> int:
+    """Reduce x modulo 2**32.
+
+    >>> wrap32(4294967295)
+    4294967295
+    >>> wrap32(4294967296)
+    0
+    >>> wrap32(-1)
+    4294967295
+    """
+    return x % 2**32
+
+
 def main():
     parser = argparse.ArgumentParser(description="Power operations tool")
     subs = parser.add_subparsers(dest="cmd", required=True)
@@ -68,6 +83,9 @@ def main():
     p = subs.add_parser("power")
     p.add_argument("base", type=int)
     p.add_argument("exp", type=int)
+    subs.add_parser("modulus")
+    w = subs.add_parser("wrap")
+    w.add_argument("x", type=int)
 
     args = parser.parse_args()
     if args.cmd == "square":
@@ -76,6 +94,10 @@ def main():
         print(cube(args.x))
     elif args.cmd == "power":
         print(power(args.base, args.exp))
+    elif args.cmd == "modulus":
+        print(2**32)
+    elif args.cmd == "wrap":
+        print(wrap32(args.x))
 
 
 if __name__ == "__main__":
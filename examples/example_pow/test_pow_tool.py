@@ -0,0 +1,62 @@
+"""Tests for pow_tool.py"""
+
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "pow_tool.py"
+
+GOLDEN = GoldenCli(SCRIPT)
+run = GOLDEN.run
+
+
+class TestPower:
+    def test_square(self):
+        stdout, _, code = run(["square", "7"])
+        assert code == 0
+        assert stdout.strip() == "49"
+
+    def test_cube(self):
+        stdout, _, _ = run(["cube", "-2"])
+        assert stdout.strip() == "-8"
+
+    def test_power(self):
+        stdout, _, _ = run(["power", "3", "4"])
+        assert stdout.strip() == "81"
+
+    def test_power_past_i32(self):
+        stdout, _, _ = run(["power", "2", "32"])
+        assert stdout.strip() == "4294967296"
+
+
+class TestModulus:
+    def test_two_to_the_32(self):
+        stdout, _, code = run(["modulus"])
+        assert code == 0
+        assert stdout.strip() == "4294967296"
+
+    def test_wrap(self):
+        stdout, _, _ = run(["wrap", "4294967301"])
+        assert stdout.strip() == "5"
+
+    def test_wrap_negative(self):
+        stdout, _, _ = run(["wrap", "--", "-1"])
+        assert stdout.strip() == "4294967295"
+
+
+class TestGolden:
+    """2 ** 32 must not be computed in i32."""
+
+    @pytest.mark.parametrize(
+        "argv,stdout",
+        [
+            (["modulus"], "4294967296\n"),
+            (["power", "2", "32"], "4294967296\n"),
+            (["wrap", "4294967301"], "5\n"),
+            (["wrap", "4294967295"], "4294967295\n"),
+        ],
+    )
+    def test_golden(self, argv, stdout):
+        GOLDEN.check(argv, stdout)
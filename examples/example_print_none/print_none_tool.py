@@ -0,0 +1,64 @@
+#!/usr/bin/env python3
+"""Print None Example - Printing optional values CLI.
+
+Printing or interpolating an optional shows the inner value when there
+is one and the literal text `None` when there is not.
+
+Examples:
+    >>> print(index_of("b", ["a", "b"]))
+    1
+    >>> print(index_of("z", ["a", "b"]))
+    None
+    >>> f"first={first([])}"
+    'first=None'
+"""
+
+import argparse
+
+
+def index_of(needle: str, items: list[str]) -> int | None:
+    """Position of the first match, or None.
+
+    >>> index_of("a", []) is None
+    True
+    """
+    for i in range(len(items)):
+        if items[i] == needle:
+            return i
+    return None
+
+
+def first(items: list[str]) -> str | None:
+    """First item, or None for an empty list.
+
+    >>> first(["x", "y"])
+    'x'
+    """
+    if len(items) == 0:
+        return None
+    return items[0]
+
+
+def main():
+    parser = argparse.ArgumentParser(description="Optional printing tool")
+    subs = parser.add_subparsers(dest="cmd", required=True)
+
+    f = subs.add_parser("find")
+    f.add_argument("needle")
+    f.add_argument("items", nargs="*")
+    fi = subs.add_parser("first")
+    fi.add_argument("items", nargs="*")
+    p = subs.add_parser("port")
+    p.add_argument("--port", type=int)
+
+    args = parser.parse_args()
+    if args.cmd == "find":
+        print(index_of(args.needle, args.items))
+    elif args.cmd == "first":
+        print(first(args.items))
+    elif args.cmd == "port":
+        print(f"port={args.port}")
+
+
+if __name__ == "__main__":
+    main()
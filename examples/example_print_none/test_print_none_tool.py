@@ -0,0 +1,70 @@
+"""Tests for print_none_tool.py"""
+
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "print_none_tool.py"
+
+GOLDEN = GoldenCli(SCRIPT)
+run = GOLDEN.run
+
+
+class TestFind:
+    def test_found(self):
+        stdout, _, code = run(["find", "b", "a", "b", "c"])
+        assert code == 0
+        assert stdout == "1\n"
+
+    def test_found_at_zero(self):
+        stdout, _, _ = run(["find", "a", "a"])
+        assert stdout == "0\n"
+
+    def test_missing_prints_none(self):
+        stdout, _, code = run(["find", "z", "a", "b"])
+        assert code == 0
+        assert stdout == "None\n"
+
+
+class TestFirst:
+    def test_some(self):
+        stdout, _, _ = run(["first", "x", "y"])
+        assert stdout == "x\n"
+
+    def test_empty_prints_none(self):
+        stdout, _, _ = run(["first"])
+        assert stdout == "None\n"
+
+    def test_literal_none_string(self):
+        stdout, _, _ = run(["first", "None"])
+        assert stdout == "None\n"
+
+
+class TestInterpolation:
+    def test_set(self):
+        stdout, _, _ = run(["port", "--port", "8080"])
+        assert stdout == "port=8080\n"
+
+    def test_unset(self):
+        stdout, _, _ = run(["port"])
+        assert stdout == "port=None\n"
+
+
+class TestGolden:
+    """Option values must print as the inner value or as `None`."""
+
+    @pytest.mark.parametrize(
+        "argv,stdout",
+        [
+            (["find", "b", "a", "b"], "1\n"),
+            (["find", "z", "a", "b"], "None\n"),
+            (["first", "x", "y"], "x\n"),
+            (["first"], "None\n"),
+            (["port", "--port", "8080"], "port=8080\n"),
+            (["port"], "port=None\n"),
+        ],
+    )
+    def test_golden(self, argv, stdout):
+        GOLDEN.check(argv, stdout)
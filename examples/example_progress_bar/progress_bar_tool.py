@@ -0,0 +1,58 @@
+#!/usr/bin/env python3
+"""Progress Bar Example - Redrawing a line with end="" and flush=True CLI.
+
+Each step prints a carriage return and the bar with `end=""`, so the
+cursor stays on the same line and the next frame overwrites it.
+`flush=True` pushes the frame out immediately instead of waiting for a
+newline. A single newline is printed after the loop.
+
+Examples:
+    >>> bar(0, 4, 8)
+    '[........]   0%'
+    >>> bar(2, 4, 8)
+    '[####....]  50%'
+    >>> bar(4, 4, 8)
+    '[########] 100%'
+"""
+
+import argparse
+import sys
+
+
+def bar(done: int, total: int, width: int) -> str:
+    """Render one frame of the bar.
+
+    >>> bar(1, 3, 3)
+    '[#..]  33%'
+    """
+    filled = done * width // total
+    percent = done * 100 // total
+    return "[" + "#" * filled + "." * (width - filled) + "] " + str(percent).rjust(3) + "%"
+
+
+def main():
+    parser = argparse.ArgumentParser(description="Progress bar tool")
+    subs = parser.add_subparsers(dest="cmd", required=True)
+
+    r = subs.add_parser("run")
+    r.add_argument("steps", type=int)
+    r.add_argument("--width", type=int, default=10)
+    d = subs.add_parser("dots")
+    d.add_argument("count", type=int)
+
+    args = parser.parse_args()
+    if args.cmd == "run":
+        if args.steps < 1:
+            print(f"Error: steps must be at least 1, got {args.steps}", file=sys.stderr)
+            sys.exit(1)
+        for i in range(args.steps + 1):
+            print("\r" + bar(i, args.steps, args.width), end="", flush=True)
+        print()
+    elif args.cmd == "dots":
+        for _ in range(args.count):
+            print(".", end="", flush=True)
+        print(" done")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,62 @@
+"""Tests for progress_bar_tool.py"""
+
+import subprocess
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "progress_bar_tool.py"
+
+GOLDEN = GoldenCli(SCRIPT)
+
+
+def run(args):
+    """Run the CLI and return (stdout, stderr, returncode).
+
+    Output is decoded by hand because text=True would translate the
+    carriage returns into newlines.
+    """
+    result = subprocess.run(["python3", str(SCRIPT)] + args, capture_output=True)
+    return result.stdout.decode(), result.stderr.decode(), result.returncode
+
+
+class TestRun:
+    def test_frames_share_one_line(self):
+        stdout, _, code = run(["run", "2", "--width", "4"])
+        assert code == 0
+        assert stdout == "\r[....]   0%\r[##..]  50%\r[####] 100%\n"
+
+    def test_no_newline_mid_loop(self):
+        stdout, _, _ = run(["run", "5"])
+        assert stdout.count("\n") == 1
+        assert stdout.endswith("\n")
+        assert stdout.count("\r") == 6
+
+    def test_final_frame(self):
+        stdout, _, _ = run(["run", "3", "--width", "6"])
+        assert stdout.rstrip("\n").split("\r")[-1] == "[######] 100%"
+
+    @pytest.mark.parametrize("steps", ["0", "-3"])
+    def test_no_steps(self, steps):
+        stdout, stderr, code = run(["run", "--", steps])
+        assert code == 1
+        assert stdout == ""
+        assert stderr == f"Error: steps must be at least 1, got {steps}\n"
+
+
+class TestDots:
+    def test_dots(self):
+        stdout, _, _ = run(["dots", "3"])
+        assert stdout == "... done\n"
+
+
+class TestGolden:
+    """Frames are not pinned here: GoldenCli decodes text, which turns \\r into \\n."""
+
+    def test_golden_dots(self):
+        GOLDEN.check(["dots", "3"], stdout="... done\n")
+
+    def test_golden_zero_steps(self):
+        GOLDEN.check(["run", "0"], stdout="", returncode=1)
@@ -0,0 +1,57 @@
+"""Tests for range_tool.py"""
+
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "range_tool.py"
+
+GOLDEN = GoldenCli(SCRIPT)
+run = GOLDEN.run
+
+
+class TestUpto:
+    def test_upto(self):
+        stdout, _, code = run(["upto", "5"])
+        assert code == 0
+        assert stdout.strip() == "0 1 2 3 4"
+
+    def test_upto_zero(self):
+        stdout, _, code = run(["upto", "0"])
+        assert code == 0
+        assert stdout == "\n"
+
+
+class TestBetween:
+    def test_between(self):
+        stdout, _, code = run(["between", "2", "5"])
+        assert code == 0
+        assert stdout.strip() == "2 3 4"
+
+    def test_between_empty(self):
+        stdout, _, _ = run(["between", "5", "2"])
+        assert stdout == "\n"
+
+
+class TestStep:
+    def test_step(self):
+        stdout, _, code = run(["step", "0", "10", "3"])
+        assert code == 0
+        assert stdout.strip() == "0 3 6 9"
+
+
+class TestGolden:
+    """Every arm must bind the fields its body reads and build result as a String"""
+
+    @pytest.mark.parametrize(
+        "argv,stdout",
+        [
+            (["upto", "5"], "0 1 2 3 4\n"),
+            (["between", "2", "5"], "2 3 4\n"),
+            (["step", "0", "10", "3"], "0 3 6 9\n"),
+        ],
+    )
+    def test_golden(self, argv, stdout):
+        GOLDEN.check(argv, stdout)
@@ -0,0 +1,73 @@
+#!/usr/bin/env python3
+"""Range In Example - Membership tests on range objects CLI.
+
+`x in range(a, b, step)` is answered arithmetically without building the
+sequence: x must lie in [a, b) for a positive step, or in (b, a] for a
+negative step, and sit a whole number of steps from a.
+
+Examples:
+    >>> in_range(4, 0, 10, 2)
+    True
+    >>> in_range(5, 0, 10, 2)
+    False
+    >>> in_range(10, 0, 10, 1)
+    False
+"""
+
+import argparse
+import sys
+
+
+def in_range(x: int, start: int, stop: int, step: int) -> bool:
+    """Membership in range(start, stop, step).
+
+    >>> in_range(7, 10, 0, -3)
+    True
+    >>> in_range(0, 10, 0, -1)
+    False
+    >>> in_range(-4, -10, 0, 3)
+    True
+    >>> in_range(1, 0, 10, 0)
+    Traceback (most recent call last):
+        ...
+    ValueError: range() arg 3 must not be zero
+    """
+    return x in range(start, stop, step)
+
+
+def percent_ok(value: int) -> bool:
+    """Whether value is a valid whole percentage.
+
+    >>> percent_ok(100)
+    True
+    >>> percent_ok(101)
+    False
+    """
+    return value in range(0, 101)
+
+
+def main():
+    parser = argparse.ArgumentParser(description="Range membership tool")
+    subs = parser.add_subparsers(dest="cmd", required=True)
+
+    c = subs.add_parser("check")
+    c.add_argument("x", type=int)
+    c.add_argument("start", type=int)
+    c.add_argument("stop", type=int)
+    c.add_argument("step", type=int, nargs="?", default=1)
+    p = subs.add_parser("percent")
+    p.add_argument("value", type=int)
+
+    args = parser.parse_args()
+    try:
+        if args.cmd == "check":
+            print(in_range(args.x, args.start, args.stop, args.step))
+        elif args.cmd == "percent":
+            print(percent_ok(args.value))
+    except ValueError as e:
+        print(f"Error: {e}", file=sys.stderr)
+        sys.exit(1)
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,43 @@
+"""Tests for range_in_tool.py"""
+
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "range_in_tool.py"
+
+run = GoldenCli(SCRIPT).run
+
+
+class TestCheck:
+    @pytest.mark.parametrize(
+        "argv,expected",
+        [
+            (["5", "0", "10", "2"], "False"),
+            (["4", "0", "10", "2"], "True"),
+            (["0", "0", "10"], "True"),
+            (["10", "0", "10"], "False"),
+            (["-1", "0", "10"], "False"),
+            (["7", "10", "0", "-3"], "True"),
+            (["8", "10", "0", "-3"], "False"),
+        ],
+    )
+    def test_membership(self, argv, expected):
+        stdout, _, code = run(["check"] + argv)
+        assert code == 0
+        assert stdout.strip() == expected
+
+    def test_zero_step(self):
+        stdout, stderr, code = run(["check", "1", "0", "10", "0"])
+        assert code == 1
+        assert stdout == ""
+        assert stderr == "Error: range() arg 3 must not be zero\n"
+
+
+class TestPercent:
+    def test_bounds(self):
+        assert run(["percent", "0"])[0].strip() == "True"
+        assert run(["percent", "100"])[0].strip() == "True"
+        assert run(["percent", "101"])[0].strip() == "False"
@@ -0,0 +1,52 @@
+#!/usr/bin/env python3
+"""Remainder Example - argparse.REMAINDER passthrough CLI.
+
+`nargs=argparse.REMAINDER` collects every argument after the tool's own
+options, including ones that look like flags, for handing to a wrapped
+command. Whether a leading `--` is kept varies between Python versions,
+so it is dropped explicitly.
+
+Examples:
+    >>> passthrough(["--", "ls", "-l"])
+    ['ls', '-l']
+    >>> passthrough(["ls", "--", "-l"])
+    ['ls', '--', '-l']
+"""
+
+import argparse
+
+
+def passthrough(rest: list[str]) -> list[str]:
+    """Arguments for the wrapped command, without a leading `--`.
+
+    >>> passthrough([])
+    []
+    >>> passthrough(["--"])
+    []
+    """
+    if len(rest) > 0 and rest[0] == "--":
+        return rest[1:]
+    return rest
+
+
+def main():
+    parser = argparse.ArgumentParser(description="Argument passthrough tool")
+    subs = parser.add_subparsers(dest="cmd", required=True)
+
+    s = subs.add_parser("show")
+    s.add_argument("--prefix", default="")
+    s.add_argument("rest", nargs=argparse.REMAINDER)
+    c = subs.add_parser("count")
+    c.add_argument("rest", nargs=argparse.REMAINDER)
+
+    args = parser.parse_args()
+    rest = passthrough(args.rest)
+    if args.cmd == "show":
+        for arg in rest:
+            print(f"{args.prefix}[{arg}]")
+    elif args.cmd == "count":
+        print(len(rest))
+
+
+if __name__ == "__main__":
+    main()
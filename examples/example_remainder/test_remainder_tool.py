@@ -0,0 +1,63 @@
+"""Tests for remainder_tool.py"""
+
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "remainder_tool.py"
+
+GOLDEN = GoldenCli(SCRIPT)
+run = GOLDEN.run
+
+
+class TestShow:
+    def test_after_double_dash(self):
+        stdout, _, code = run(["show", "--", "grep", "-r", "--color", "x"])
+        assert code == 0
+        assert stdout.splitlines() == ["[grep]", "[-r]", "[--color]", "[x]"]
+
+    def test_without_double_dash(self):
+        stdout, _, _ = run(["show", "echo", "-n", "hi"])
+        assert stdout.splitlines() == ["[echo]", "[-n]", "[hi]"]
+
+    def test_own_option_before_rest(self):
+        stdout, _, _ = run(["show", "--prefix", "> ", "--", "ls", "--prefix"])
+        assert stdout.splitlines() == ["> [ls]", "> [--prefix]"]
+
+    def test_later_double_dash_kept(self):
+        stdout, _, _ = run(["show", "--", "git", "log", "--", "file.py"])
+        assert stdout.splitlines() == ["[git]", "[log]", "[--]", "[file.py]"]
+
+    def test_empty(self):
+        stdout, _, code = run(["show", "--"])
+        assert code == 0
+        assert stdout == ""
+
+
+class TestCount:
+    def test_count(self):
+        stdout, _, _ = run(["count", "--", "a", "-b", "--c"])
+        assert stdout.strip() == "3"
+
+    def test_none(self):
+        stdout, _, _ = run(["count"])
+        assert stdout.strip() == "0"
+
+
+class TestGolden:
+    """REMAINDER must collect trailing args, flags included, into a Vec<String>."""
+
+    @pytest.mark.parametrize(
+        "argv,stdout",
+        [
+            (["show", "--", "grep", "-r", "x"], "[grep]\n[-r]\n[x]\n"),
+            (["show", "echo", "-n"], "[echo]\n[-n]\n"),
+            (["show", "--prefix", ">", "--", "a"], ">[a]\n"),
+            (["count", "--", "a", "-b", "--c"], "3\n"),
+            (["count"], "0\n"),
+        ],
+    )
+    def test_golden(self, argv, stdout):
+        GOLDEN.check(argv, stdout)
@@ -4,8 +4,8 @@
 Examples:
     >>> replace_char("hello", "l", "x")
     'hexxo'
-    >>> replace_all("abc", "X")
-    'XXX'
+    >>> replace_all("aXbXc", "X", "Y")
+    'aYbYc'
 """
 
 import argparse
@@ -24,20 +24,25 @@ def replace_char(text: str, old: str, new: str) -> str:
     return text.replace(old, new)
 
 
-def replace_all(text: str, new: str) -> str:
-    """Replace each character with new.
+def replace_all(text: str, old: str, new: str) -> str:
+    """Replace each character equal to old with new, one character at a time.
 
-    >>> replace_all("abc", "X")
-    'XXX'
-    >>> replace_all("", "X")
+    >>> replace_all("aXbXc", "X", "Y")
+    'aYbYc'
+    >>> replace_all("", "a", "X")
     ''
-    >>> replace_all("hello", "*")
-    '*****'
+    >>> replace_all("hello", "l", "*")
+    'he**o'
+    >>> replace_all("abc", "z", "*")
+    'abc'
     """
     result = ""
     i = 0
     while i < len(text):
-        result = result + new
+        if text[i] == old:
+            result = result + new
+        else:
+            result = result + text[i]
         i = i + 1
     return result
 
@@ -55,6 +60,7 @@ def main():
     f.add_argument("new")
     a = subs.add_parser("all")
     a.add_argument("text")
+    a.add_argument("old")
     a.add_argument("new")
 
     args = parser.parse_args()
@@ -73,7 +79,7 @@ def main():
             i = i + 1
         print(result)
     elif args.cmd == "all":
-        print(replace_all(args.text, args.new))
+        print(replace_all(args.text, args.old, args.new))
 
 
 if __name__ == "__main__":
@@ -0,0 +1,47 @@
+"""Tests for replace_tool.py"""
+
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "replace_tool.py"
+
+GOLDEN = GoldenCli(SCRIPT)
+run = GOLDEN.run
+
+
+class TestAll:
+    def test_replaces_only_matches(self):
+        stdout, _, code = run(["all", "aXbXc", "X", "Y"])
+        assert code == 0
+        assert stdout.strip() == "aYbYc"
+
+    def test_no_match(self):
+        stdout, _, _ = run(["all", "abc", "z", "Y"])
+        assert stdout.strip() == "abc"
+
+    def test_missing_old_is_usage_error(self):
+        _, _, code = run(["all", "abc", "X"])
+        assert code == 2
+
+
+class TestChar:
+    def test_char(self):
+        stdout, _, _ = run(["char", "banana", "a", "o"])
+        assert stdout.strip() == "bonono"
+
+
+class TestGolden:
+    """result starts as "" and grows by +; it must compile as an owned String"""
+
+    @pytest.mark.parametrize(
+        "argv,stdout",
+        [
+            (["all", "aXbXc", "X", "Y"], "aYbYc\n"),
+            (["first", "banana", "*"], "*anana\n"),
+        ],
+    )
+    def test_golden(self, argv, stdout):
+        GOLDEN.check(argv, stdout)
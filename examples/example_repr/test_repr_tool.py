@@ -0,0 +1,43 @@
+"""Tests for repr_tool.py"""
+
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "repr_tool.py"
+
+GOLDEN = GoldenCli(SCRIPT)
+run = GOLDEN.run
+
+
+class TestString:
+    def test_string(self):
+        stdout, _, code = run(["string", "hello"])
+        assert code == 0
+        assert stdout.strip() == "'hello'"
+
+
+class TestEscape:
+    def test_tab(self):
+        stdout, _, _ = run(["escape", "tab"])
+        assert stdout.strip() == "'\\t'"
+
+    def test_other(self):
+        stdout, _, _ = run(["escape", "abc"])
+        assert stdout.strip() == "'abc'"
+
+
+class TestGolden:
+    """Every subcommand arm must bind the fields its body reads"""
+
+    @pytest.mark.parametrize(
+        "argv,stdout",
+        [
+            (["string", "hello"], "'hello'\n"),
+            (["number", "42"], "42\n"),
+        ],
+    )
+    def test_golden(self, argv, stdout):
+        GOLDEN.check(argv, stdout)
@@ -0,0 +1,37 @@
+#!/usr/bin/env python3
+"""Required Option Example - Flags declared with required=True CLI.
+
+`--user` and `--port` look optional but are declared `required=True`,
+so argparse exits with status 2 and a "the following arguments are
+required" error when either is omitted. `--role` stays optional.
+
+Examples:
+    >>> describe("ada", 22, None)
+    'ada:22'
+    >>> describe("ada", 22, "admin")
+    'ada:22 (admin)'
+"""
+
+import argparse
+
+
+def describe(user: str, port: int, role: str | None) -> str:
+    """Summarize the connection settings."""
+    text = f"{user}:{port}"
+    if role is not None:
+        text = text + f" ({role})"
+    return text
+
+
+def main():
+    parser = argparse.ArgumentParser(description="Required option tool")
+    parser.add_argument("--user", required=True)
+    parser.add_argument("--port", type=int, required=True)
+    parser.add_argument("--role")
+
+    args = parser.parse_args()
+    print(describe(args.user, args.port, args.role))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,38 @@
+"""Tests for required_option_tool.py"""
+
+from pathlib import Path
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "required_option_tool.py"
+
+run = GoldenCli(SCRIPT).run
+
+
+class TestPresent:
+    def test_all_required(self):
+        stdout, _, code = run(["--user", "ada", "--port", "22"])
+        assert code == 0
+        assert stdout.strip() == "ada:22"
+
+    def test_with_optional(self):
+        stdout, _, _ = run(["--port", "22", "--user", "ada", "--role", "admin"])
+        assert stdout.strip() == "ada:22 (admin)"
+
+
+class TestMissing:
+    def test_missing_one(self):
+        stdout, stderr, code = run(["--user", "ada"])
+        assert code == 2
+        assert stdout == ""
+        assert "--port" in stderr
+
+    def test_missing_all(self):
+        _, stderr, code = run([])
+        assert code == 2
+        assert "--user" in stderr
+        assert "--port" in stderr
+
+    def test_optional_not_reported(self):
+        _, stderr, _ = run([])
+        assert "--role" not in stderr.splitlines()[-1]
@@ -0,0 +1,61 @@
+"""Tests for reversed_tool.py"""
+
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "reversed_tool.py"
+
+GOLDEN = GoldenCli(SCRIPT)
+run = GOLDEN.run
+
+
+class TestString:
+    def test_ascii(self):
+        stdout, _, code = run(["string", "abc"])
+        assert code == 0
+        assert stdout == "cba\n"
+
+    def test_multibyte(self):
+        stdout, _, code = run(["string", "áé"])
+        assert code == 0
+        assert stdout == "éá\n"
+
+    def test_mixed_width(self):
+        stdout, _, _ = run(["string", "a€b😀"])
+        assert stdout == "😀b€a\n"
+
+    def test_empty(self):
+        stdout, _, _ = run(["string", ""])
+        assert stdout == "\n"
+
+
+class TestDigits:
+    def test_digits(self):
+        stdout, _, _ = run(["digits", "1230"])
+        assert stdout.strip() == "321"
+
+
+class TestWords:
+    def test_words(self):
+        stdout, _, _ = run(["words", "one_two_three"])
+        assert stdout.strip() == "three_two_one"
+
+
+class TestGolden:
+    """The reverse loop must walk chars, not bytes."""
+
+    @pytest.mark.parametrize(
+        "argv,stdout",
+        [
+            (["string", "abc"], "cba\n"),
+            (["string", "áé"], "éá\n"),
+            (["string", "a€b😀"], "😀b€a\n"),
+            (["string", ""], "\n"),
+            (["digits", "1230"], "321\n"),
+        ],
+    )
+    def test_golden(self, argv, stdout):
+        GOLDEN.check(argv, stdout)
@@ -0,0 +1,73 @@
+#!/usr/bin/env python3
+"""Rjust Table Example - Right-aligned numeric columns CLI.
+
+Numbers are converted with `str()` and then padded with `rjust`/`ljust`,
+so columns line up regardless of digit count or sign.
+
+Examples:
+    >>> str(42).rjust(6)
+    '    42'
+    >>> format_row("total", 1234, 8)
+    'total     1234'
+    >>> rule(5, "=")
+    '====='
+"""
+
+import argparse
+
+
+def format_row(label: str, n: int, width: int) -> str:
+    """Left-aligned label followed by a right-aligned number.
+
+    >>> format_row("a", -7, 4)
+    'a       -7'
+    >>> format_row("abcdef", 123456, 3)
+    'abcdef123456'
+    """
+    return label.ljust(6) + str(n).rjust(width)
+
+
+def rule(width: int, char: str = "-") -> str:
+    """Separator line of width copies of char; zero or negative is empty.
+
+    >>> rule(3)
+    '---'
+    >>> rule(-2)
+    ''
+    >>> rule(2, "=-")
+    '=-=-'
+    """
+    return char * width
+
+
+def main():
+    parser = argparse.ArgumentParser(description="Right-aligned table tool")
+    subs = parser.add_subparsers(dest="cmd", required=True)
+
+    c = subs.add_parser("column")
+    c.add_argument("nums", type=int, nargs="+")
+    c.add_argument("--width", type=int, default=6)
+    sq = subs.add_parser("squares")
+    sq.add_argument("n", type=int)
+    r = subs.add_parser("rule")
+    r.add_argument("--width", type=int, default=40)
+    r.add_argument("--char", default="-")
+
+    args = parser.parse_args()
+    if args.cmd == "column":
+        total = 0
+        for n in args.nums:
+            print(str(n).rjust(args.width))
+            total = total + n
+        print("-" * args.width)
+        print(str(total).rjust(args.width))
+    elif args.cmd == "squares":
+        width = len(str(args.n * args.n))
+        for i in range(1, args.n + 1):
+            print(str(i).rjust(width) + " " + str(i * i).rjust(width))
+    elif args.cmd == "rule":
+        print(rule(args.width, args.char))
+
+
+if __name__ == "__main__":
+    main()
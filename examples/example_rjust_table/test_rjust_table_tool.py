@@ -0,0 +1,82 @@
+"""Tests for rjust_table_tool.py"""
+
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "rjust_table_tool.py"
+
+GOLDEN = GoldenCli(SCRIPT)
+run = GOLDEN.run
+
+
+class TestColumn:
+    def test_right_aligned_column(self):
+        stdout, _, code = run(["column", "5", "120", "-3", "4000"])
+        assert code == 0
+        assert stdout.splitlines() == [
+            "     5",
+            "   120",
+            "    -3",
+            "  4000",
+            "------",
+            "  4122",
+        ]
+
+    def test_custom_width(self):
+        stdout, _, _ = run(["column", "7", "--width", "3"])
+        assert stdout.splitlines() == ["  7", "---", "  7"]
+
+    def test_number_wider_than_column(self):
+        stdout, _, _ = run(["column", "1234567", "--width", "3"])
+        assert stdout.splitlines()[0] == "1234567"
+
+
+class TestSquares:
+    def test_aligned_to_widest(self):
+        stdout, _, _ = run(["squares", "4"])
+        assert stdout.splitlines() == [" 1  1", " 2  4", " 3  9", " 4 16"]
+
+    def test_all_lines_equal_width(self):
+        stdout, _, _ = run(["squares", "12"])
+        assert len({len(line) for line in stdout.splitlines()}) == 1
+
+
+class TestRule:
+    def test_default_forty_dashes(self):
+        stdout, _, code = run(["rule"])
+        assert code == 0
+        assert stdout == "-" * 40 + "\n"
+        assert len(stdout.strip()) == 40
+
+    def test_char(self):
+        stdout, _, _ = run(["rule", "--width", "5", "--char", "="])
+        assert stdout == "=====\n"
+
+    def test_multichar(self):
+        stdout, _, _ = run(["rule", "--width", "3", "--char", "ab"])
+        assert stdout == "ababab\n"
+
+    def test_zero_and_negative_width(self):
+        for width in ["0", "-5"]:
+            stdout, _, code = run(["rule", "--width", width])
+            assert code == 0
+            assert stdout == "\n"
+
+
+class TestGolden:
+    """s * n must lower to s.repeat(n) with negative n clamped to zero."""
+
+    @pytest.mark.parametrize(
+        "argv,stdout",
+        [
+            (["rule"], "----------------------------------------\n"),
+            (["rule", "--width", "3", "--char", "ab"], "ababab\n"),
+            (["rule", "--width", "-5"], "\n"),
+            (["column", "7", "--width", "3"], "  7\n---\n  7\n"),
+        ],
+    )
+    def test_golden(self, argv, stdout):
+        GOLDEN.check(argv, stdout)
@@ -0,0 +1,72 @@
+#!/usr/bin/env python3
+"""Setops Example - Set union, intersection and difference CLI.
+
+Each operand is a whitespace-separated list; duplicates collapse when
+it becomes a set. Results print sorted, so they do not depend on hash
+order.
+
+Examples:
+    >>> sorted(to_set("b a b") | to_set("c"))
+    ['a', 'b', 'c']
+    >>> sorted(to_set("a b c") & to_set("b c d"))
+    ['b', 'c']
+    >>> sorted(to_set("a b c") - to_set("b"))
+    ['a', 'c']
+"""
+
+import argparse
+
+
+def to_set(text: str) -> set[str]:
+    """Words of text as a set.
+
+    >>> to_set("x x x")
+    {'x'}
+    >>> to_set("")
+    set()
+    """
+    result = set()
+    for word in text.split():
+        result.add(word)
+    return result
+
+
+def render(items: set[str]) -> str:
+    """Sorted, space-separated members.
+
+    >>> render({"b", "a"})
+    'a b'
+    """
+    return " ".join(sorted(items))
+
+
+def main():
+    parser = argparse.ArgumentParser(description="Set operations tool")
+    subs = parser.add_subparsers(dest="cmd", required=True)
+
+    u = subs.add_parser("union")
+    u.add_argument("left")
+    u.add_argument("right")
+    i = subs.add_parser("inter")
+    i.add_argument("left")
+    i.add_argument("right")
+    d = subs.add_parser("diff")
+    d.add_argument("left")
+    d.add_argument("right")
+    h = subs.add_parser("has")
+    h.add_argument("items")
+    h.add_argument("word")
+
+    args = parser.parse_args()
+    if args.cmd == "union":
+        print(render(to_set(args.left) | to_set(args.right)))
+    elif args.cmd == "inter":
+        print(render(to_set(args.left) & to_set(args.right)))
+    elif args.cmd == "diff":
+        print(render(to_set(args.left) - to_set(args.right)))
+    elif args.cmd == "has":
+        print(args.word in to_set(args.items))
+
+
+if __name__ == "__main__":
+    main()
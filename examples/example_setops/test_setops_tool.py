@@ -0,0 +1,74 @@
+"""Tests for setops_tool.py"""
+
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "setops_tool.py"
+
+GOLDEN = GoldenCli(SCRIPT)
+run = GOLDEN.run
+
+
+class TestUnion:
+    def test_union(self):
+        stdout, _, code = run(["union", "c a", "b a"])
+        assert code == 0
+        assert stdout == "a b c\n"
+
+    def test_duplicates_collapse(self):
+        stdout, _, _ = run(["union", "x x", "x"])
+        assert stdout == "x\n"
+
+    def test_empty_side(self):
+        stdout, _, _ = run(["union", "", "b a"])
+        assert stdout == "a b\n"
+
+
+class TestInter:
+    def test_inter(self):
+        stdout, _, _ = run(["inter", "a b c", "b c d"])
+        assert stdout == "b c\n"
+
+    def test_disjoint(self):
+        stdout, _, _ = run(["inter", "a", "b"])
+        assert stdout == "\n"
+
+
+class TestDiff:
+    def test_diff(self):
+        stdout, _, _ = run(["diff", "a b c", "b"])
+        assert stdout == "a c\n"
+
+    def test_not_symmetric(self):
+        stdout, _, _ = run(["diff", "b", "a b c"])
+        assert stdout == "\n"
+
+
+class TestHas:
+    def test_present(self):
+        stdout, _, _ = run(["has", "a b c", "b"])
+        assert stdout.strip() == "True"
+
+    def test_whole_word_only(self):
+        stdout, _, _ = run(["has", "abc", "b"])
+        assert stdout.strip() == "False"
+
+
+class TestGolden:
+    """Set operators must lower to BTreeSet union/intersection/difference."""
+
+    @pytest.mark.parametrize(
+        "argv,stdout",
+        [
+            (["union", "c a", "b a"], "a b c\n"),
+            (["inter", "a b c", "b c d"], "b c\n"),
+            (["diff", "a b c", "b"], "a c\n"),
+            (["inter", "a", "b"], "\n"),
+            (["has", "a b c", "b"], "True\n"),
+        ],
+    )
+    def test_golden(self, argv, stdout):
+        GOLDEN.check(argv, stdout)
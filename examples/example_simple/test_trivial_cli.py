@@ -13,9 +13,13 @@ from pathlib import Path
 
 import pytest
 
+from reprorusted_python_cli.golden import GoldenCli
+
 # Path to the CLI script
 SCRIPT = Path(__file__).parent / "trivial_cli.py"
 
+GOLDEN = GoldenCli(SCRIPT)
+
 
 def run_cli(*args):
     """
@@ -193,3 +197,22 @@ class TestEdgeCases:
         assert result.returncode == 0, "Version should succeed"
         assert "1.0.0" in result.stdout, "Should show version"
         # When --version is present, other args are ignored
+
+
+class TestGolden:
+    """End-to-end cases checked against Python and the transpiled binary"""
+
+    @pytest.mark.parametrize(
+        "argv,stdout",
+        [
+            (["--name", "Alice"], "Hello, Alice!\n"),
+            (["--name", "Dr. Smith"], "Hello, Dr. Smith!\n"),
+            (["--name", ""], "Hello, !\n"),
+            (["--version"], "1.0.0\n"),
+        ],
+    )
+    def test_golden(self, argv, stdout):
+        GOLDEN.check(argv, stdout)
+
+    def test_golden_missing_name(self):
+        GOLDEN.check([], stdout="", returncode=2)
@@ -0,0 +1,94 @@
+#!/usr/bin/env python3
+"""Sort Multikey Example - Sorting by tuple keys CLI.
+
+A key function returning a tuple sorts by the first field, then breaks
+ties with the next. Negating a numeric field sorts that field
+descending while the others stay ascending.
+
+Examples:
+    >>> staff = parse_all(["ada:eng:90", "bob:ops:70", "cy:eng:95"])
+    >>> [e.name for e in by_dept(staff)]
+    ['cy', 'ada', 'bob']
+    >>> [e.name for e in leaderboard(staff)]
+    ['cy', 'ada', 'bob']
+"""
+
+import argparse
+import sys
+from dataclasses import dataclass
+
+
+@dataclass
+class Employee:
+    name: str
+    dept: str
+    score: int
+
+
+def parse_employee(text: str) -> Employee:
+    """Parse a name:dept:score record.
+
+    >>> parse_employee("ada:eng:90")
+    Employee(name='ada', dept='eng', score=90)
+    >>> parse_employee("ada:eng")
+    Traceback (most recent call last):
+        ...
+    ValueError: expected name:dept:score, got 'ada:eng'
+    """
+    fields = text.split(":")
+    if len(fields) != 3:
+        raise ValueError(f"expected name:dept:score, got '{text}'")
+    return Employee(fields[0], fields[1], int(fields[2]))
+
+
+def parse_all(records: list[str]) -> list[Employee]:
+    """Parse every record."""
+    return [parse_employee(r) for r in records]
+
+
+def by_dept(staff: list[Employee]) -> list[Employee]:
+    """Department ascending, then score descending.
+
+    >>> staff = parse_all(["b:x:1", "a:y:5", "c:x:3"])
+    >>> [e.name for e in by_dept(staff)]
+    ['c', 'b', 'a']
+    """
+    return sorted(staff, key=lambda e: (e.dept, -e.score))
+
+
+def leaderboard(staff: list[Employee]) -> list[Employee]:
+    """Score descending, then name ascending.
+
+    >>> staff = parse_all(["zed:x:5", "amy:y:5", "bo:x:9"])
+    >>> [e.name for e in leaderboard(staff)]
+    ['bo', 'amy', 'zed']
+    """
+    return sorted(staff, key=lambda e: (-e.score, e.name))
+
+
+def main():
+    parser = argparse.ArgumentParser(description="Multi-key sort tool")
+    subs = parser.add_subparsers(dest="cmd", required=True)
+
+    d = subs.add_parser("dept")
+    d.add_argument("records", nargs="+")
+    lb = subs.add_parser("leaderboard")
+    lb.add_argument("records", nargs="+")
+
+    args = parser.parse_args()
+    try:
+        staff = parse_all(args.records)
+    except ValueError as e:
+        print(f"Error: {e}", file=sys.stderr)
+        sys.exit(1)
+
+    if args.cmd == "dept":
+        ordered = by_dept(staff)
+    else:
+        ordered = leaderboard(staff)
+    for e in ordered:
+        print(f"{e.name} {e.dept} {e.score}")
+
+
+if __name__ == "__main__":
+    main()
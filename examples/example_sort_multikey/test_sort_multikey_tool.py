@@ -0,0 +1,72 @@
+"""Tests for sort_multikey_tool.py"""
+
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "sort_multikey_tool.py"
+
+GOLDEN = GoldenCli(SCRIPT)
+run = GOLDEN.run
+
+STAFF = ["ada:eng:90", "bob:ops:70", "cy:eng:95", "dee:ops:85", "eve:eng:90", "fay:ops:99"]
+
+
+class TestDept:
+    def test_two_level(self):
+        stdout, _, code = run(["dept"] + STAFF)
+        assert code == 0
+        assert stdout.splitlines() == [
+            "cy eng 95",
+            "ada eng 90",
+            "eve eng 90",
+            "fay ops 99",
+            "dee ops 85",
+            "bob ops 70",
+        ]
+
+    def test_negative_scores(self):
+        stdout, _, _ = run(["dept", "a:x:-5", "b:x:-1", "c:x:0"])
+        assert stdout.splitlines() == ["c x 0", "b x -1", "a x -5"]
+
+
+class TestLeaderboard:
+    def test_score_then_name(self):
+        stdout, _, code = run(["leaderboard"] + STAFF)
+        assert code == 0
+        assert stdout.splitlines() == [
+            "fay ops 99",
+            "cy eng 95",
+            "ada eng 90",
+            "eve eng 90",
+            "dee ops 85",
+            "bob ops 70",
+        ]
+
+    def test_name_breaks_tie(self):
+        stdout, _, _ = run(["leaderboard", "zed:x:5", "amy:y:5"])
+        assert stdout.splitlines() == ["amy y 5", "zed x 5"]
+
+
+class TestErrors:
+    def test_malformed_record(self):
+        _, stderr, code = run(["dept", "ada:eng"])
+        assert code == 1
+        assert "Error: expected name:dept:score, got 'ada:eng'" in stderr
+
+
+class TestGolden:
+    """Tuple keys must lower to sort_by_key with a tuple, negating numeric fields."""
+
+    @pytest.mark.parametrize(
+        "argv,stdout",
+        [
+            (["dept", "b:x:1", "a:y:5", "c:x:3"], "c x 3\nb x 1\na y 5\n"),
+            (["leaderboard", "zed:x:5", "amy:y:5", "bo:x:9"], "bo x 9\namy y 5\nzed x 5\n"),
+            (["dept", "a:x:-5", "b:x:-1"], "b x -1\na x -5\n"),
+        ],
+    )
+    def test_golden(self, argv, stdout):
+        GOLDEN.check(argv, stdout)
@@ -0,0 +1,61 @@
+#!/usr/bin/env python3
+"""Sort Stable Example - Stable key sorting CLI.
+
+Python's sort is stable: items whose keys compare equal keep their
+input order. That holds for `reverse=True` too, which reverses the key
+order but not the order among ties. The Rust side must match this, so
+`sort_by_key` (stable) is correct and `sort_unstable_by_key` is not.
+
+Examples:
+    >>> by_length(["ccc", "a", "bb", "b", "aa"])
+    ['a', 'b', 'bb', 'aa', 'ccc']
+    >>> by_length(["ccc", "a", "bb", "b", "aa"], reverse=True)
+    ['ccc', 'bb', 'aa', 'a', 'b']
+"""
+
+import argparse
+
+
+def by_length(words: list[str], reverse: bool = False) -> list[str]:
+    """Sort by length, keeping input order among equal lengths.
+
+    >>> by_length(["dog", "cat", "ant"])
+    ['dog', 'cat', 'ant']
+    """
+    return sorted(words, key=len, reverse=reverse)
+
+
+def by_initial(words: list[str]) -> list[str]:
+    """Sort by first letter only, keeping input order among ties.
+
+    Empty words have no first letter and sort before everything else.
+
+    >>> by_initial(["beta", "alpha", "bravo", "apple"])
+    ['alpha', 'apple', 'beta', 'bravo']
+    >>> by_initial(["bravo", "beta"])
+    ['bravo', 'beta']
+    >>> by_initial(["b", "", "a", ""])
+    ['', '', 'a', 'b']
+    """
+    return sorted(words, key=lambda w: w[:1])
+
+
+def main():
+    parser = argparse.ArgumentParser(description="Stable sort tool")
+    subs = parser.add_subparsers(dest="cmd", required=True)
+
+    ln = subs.add_parser("length")
+    ln.add_argument("words", nargs="+")
+    ln.add_argument("--reverse", action="store_true")
+    ini = subs.add_parser("initial")
+    ini.add_argument("words", nargs="+")
+
+    args = parser.parse_args()
+    if args.cmd == "length":
+        print(" ".join(by_length(args.words, args.reverse)))
+    elif args.cmd == "initial":
+        print(" ".join(by_initial(args.words)))
+
+
+if __name__ == "__main__":
+    main()
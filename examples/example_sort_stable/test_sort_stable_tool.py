@@ -0,0 +1,33 @@
+"""Tests for sort_stable_tool.py"""
+
+from pathlib import Path
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "sort_stable_tool.py"
+
+run = GoldenCli(SCRIPT).run
+
+
+class TestStability:
+    def test_equal_lengths_keep_input_order(self):
+        stdout, _, code = run(["length", "pear", "fig", "plum", "kiwi", "yam"])
+        assert code == 0
+        assert stdout.strip() == "fig yam pear plum kiwi"
+
+    def test_all_ties_unchanged(self):
+        stdout, _, _ = run(["length", "dog", "cat", "ant", "bee"])
+        assert stdout.strip() == "dog cat ant bee"
+
+    def test_reverse_keeps_tie_order(self):
+        stdout, _, _ = run(["length", "--reverse", "pear", "fig", "plum", "yam"])
+        assert stdout.strip() == "pear plum fig yam"
+
+    def test_initial(self):
+        stdout, _, _ = run(["initial", "bravo", "alpha", "beta", "apple"])
+        assert stdout.strip() == "alpha apple bravo beta"
+
+    def test_initial_empty_word_first(self):
+        stdout, _, code = run(["initial", "b", "", "a"])
+        assert code == 0
+        assert stdout == " a b\n"
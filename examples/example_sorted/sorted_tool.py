@@ -6,6 +6,8 @@ Examples:
     [1, 2, 5, 8, 9]
     >>> sort_desc([5, 2, 8, 1, 9])
     [9, 8, 5, 2, 1]
+    >>> sort_builtin([5, 2, 8, 1, 9])
+    [1, 2, 5, 8, 9]
 """
 
 import argparse
@@ -82,6 +84,27 @@ def sort_alpha(words: list) -> list:
     return result
 
 
+def sort_builtin(nums: list[int], reverse: bool = False) -> list[int]:
+    """Sort with the sorted() builtin instead of a hand-written loop.
+
+    >>> sort_builtin([3, 1, 2], reverse=True)
+    [3, 2, 1]
+    >>> sort_builtin([])
+    []
+    """
+    return sorted(nums, reverse=reverse)
+
+
+def sort_in_place(nums: list[int]) -> list[int]:
+    """Sort with list.sort().
+
+    >>> sort_in_place([2, -1, 0])
+    [-1, 0, 2]
+    """
+    nums.sort()
+    return nums
+
+
 def main():
     parser = argparse.ArgumentParser(description="Sorting tool")
     subs = parser.add_subparsers(dest="cmd", required=True)
@@ -102,6 +125,11 @@ def main():
     al.add_argument("a")
     al.add_argument("b")
     al.add_argument("c")
+    b = subs.add_parser("builtin")
+    b.add_argument("nums", type=int, nargs="+")
+    b.add_argument("--reverse", action="store_true")
+    ip = subs.add_parser("inplace")
+    ip.add_argument("nums", type=int, nargs="+")
 
     args = parser.parse_args()
     if args.cmd == "asc":
@@ -113,6 +141,10 @@ def main():
     elif args.cmd == "alpha":
         words = sort_alpha([args.a, args.b, args.c])
         print(f"{words[0]} {words[1]} {words[2]}")
+    elif args.cmd == "builtin":
+        print(" ".join(str(x) for x in sort_builtin(args.nums, args.reverse)))
+    elif args.cmd == "inplace":
+        print(" ".join(str(x) for x in sort_in_place(args.nums)))
 
 
 if __name__ == "__main__":
@@ -0,0 +1,74 @@
+"""Tests for sorted_tool.py"""
+
+import random
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "sorted_tool.py"
+
+GOLDEN = GoldenCli(SCRIPT)
+run = GOLDEN.run
+
+# A fixed 1000-element input, so the golden stdout is reproducible
+_rng = random.Random(1516)
+THOUSAND = [_rng.randint(-10_000, 10_000) for _ in range(1000)]
+
+
+class TestHandWritten:
+    def test_asc(self):
+        stdout, _, code = run(["asc", "5", "2", "8", "1", "9"])
+        assert code == 0
+        assert stdout.strip() == "1 2 5 8 9"
+
+    def test_desc(self):
+        stdout, _, _ = run(["desc", "5", "2", "8", "1", "9"])
+        assert stdout.strip() == "9 8 5 2 1"
+
+
+class TestBuiltin:
+    def test_sorted(self):
+        stdout, _, _ = run(["builtin", "3", "-1", "2", "2"])
+        assert stdout.strip() == "-1 2 2 3"
+
+    def test_reverse(self):
+        stdout, _, _ = run(["builtin", "--reverse", "3", "-1", "2"])
+        assert stdout.strip() == "3 2 -1"
+
+    def test_inplace(self):
+        stdout, _, _ = run(["inplace", "4", "3", "2", "1"])
+        assert stdout.strip() == "1 2 3 4"
+
+    def test_thousand_elements(self):
+        stdout, _, code = run(["builtin"] + [str(n) for n in THOUSAND])
+        assert code == 0
+        assert [int(x) for x in stdout.split()] == sorted(THOUSAND)
+
+
+class TestGolden:
+    """sorted() and list.sort() must lower to Rust's sort at any length."""
+
+    @pytest.mark.parametrize(
+        "argv,stdout",
+        [
+            (["builtin", "3", "-1", "2", "2"], "-1 2 2 3\n"),
+            (["builtin", "--reverse", "3", "-1", "2"], "3 2 -1\n"),
+            (["inplace", "4", "3", "2", "1"], "1 2 3 4\n"),
+        ],
+    )
+    def test_golden(self, argv, stdout):
+        GOLDEN.check(argv, stdout)
+
+    @pytest.mark.parametrize(
+        "cmd,expected",
+        [
+            (["builtin"], sorted(THOUSAND)),
+            (["builtin", "--reverse"], sorted(THOUSAND, reverse=True)),
+            (["inplace"], sorted(THOUSAND)),
+        ],
+    )
+    def test_golden_thousand(self, cmd, expected):
+        argv = cmd + [str(n) for n in THOUSAND]
+        GOLDEN.check(argv, " ".join(str(n) for n in expected) + "\n")
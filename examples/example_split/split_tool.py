@@ -11,6 +11,7 @@ Examples:
 """
 
 import argparse
+import sys
 
 
 def split_underscore(text: str) -> list:
@@ -56,17 +57,34 @@ def main():
     d.add_argument("text")
     dt = subs.add_parser("dot")
     dt.add_argument("text")
+    f = subs.add_parser("fmt")
+    f.add_argument("text")
+    e = subs.add_parser("each")
+    e.add_argument("text")
+    e.add_argument("--sep", default=".")
 
     args = parser.parse_args()
     if args.cmd == "underscore":
         parts = split_underscore(args.text)
-        print(parts[0] + " " + parts[1] + " " + parts[2])
+        print(" ".join(parts))
     elif args.cmd == "dash":
         parts = split_dash(args.text)
-        print(parts[0] + " " + parts[1] + " " + parts[2])
+        print(" ".join(parts))
     elif args.cmd == "dot":
         parts = split_dot(args.text)
-        print(parts[0] + " " + parts[1] + " " + parts[2])
+        print(" ".join(parts))
+    elif args.cmd == "fmt":
+        parts = split_underscore(args.text)
+        if len(parts) != 3:
+            print(f"Error: expected 3 fields, got {len(parts)}", file=sys.stderr)
+            sys.exit(1)
+        print(f"{parts[0]} {parts[1]} {parts[2]}")
+    elif args.cmd == "each":
+        if args.sep == "":
+            print("Error: empty separator", file=sys.stderr)
+            sys.exit(1)
+        for part in args.text.split(args.sep):
+            print(part)
 
 
 if __name__ == "__main__":
@@ -0,0 +1,107 @@
+"""Tests for split_tool.py"""
+
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "split_tool.py"
+
+GOLDEN = GoldenCli(SCRIPT)
+run = GOLDEN.run
+
+
+class TestSplit:
+    def test_underscore(self):
+        stdout, _, code = run(["underscore", "hello_world_test"])
+        assert code == 0
+        assert stdout == "hello world test\n"
+
+    def test_dash(self):
+        stdout, _, _ = run(["dash", "2025-11-29"])
+        assert stdout == "2025 11 29\n"
+
+    def test_dot(self):
+        stdout, _, _ = run(["dot", "a.b.c"])
+        assert stdout == "a b c\n"
+
+    def test_empty_fields(self):
+        stdout, _, _ = run(["underscore", "_x_"])
+        assert stdout == " x \n"
+
+    def test_more_than_three(self):
+        stdout, _, _ = run(["dot", "192.168.1.1"])
+        assert stdout == "192 168 1 1\n"
+
+    def test_single_field(self):
+        stdout, _, _ = run(["dash", "solo"])
+        assert stdout == "solo\n"
+
+
+class TestFmt:
+    def test_three_fields(self):
+        stdout, _, code = run(["fmt", "hello_world_test"])
+        assert code == 0
+        assert stdout == "hello world test\n"
+
+    def test_matches_join(self):
+        via_fmt, _, _ = run(["fmt", "a_b_c"])
+        via_join, _, _ = run(["underscore", "a_b_c"])
+        assert via_fmt == via_join
+
+    def test_wrong_count(self):
+        stdout, stderr, code = run(["fmt", "a_b"])
+        assert code == 1
+        assert stdout == ""
+        assert "expected 3 fields" in stderr
+
+
+class TestEach:
+    def test_one_per_line(self):
+        stdout, _, code = run(["each", "a.b.c.d.e"])
+        assert code == 0
+        assert stdout == "a\nb\nc\nd\ne\n"
+
+    def test_custom_sep(self):
+        stdout, _, _ = run(["each", "x,y", "--sep", ","])
+        assert stdout == "x\ny\n"
+
+    def test_empty_fields(self):
+        stdout, _, _ = run(["each", ".a."])
+        assert stdout == "\na\n\n"
+
+    def test_no_sep(self):
+        stdout, _, _ = run(["each", "abc"])
+        assert stdout == "abc\n"
+
+    def test_empty_sep(self):
+        stdout, stderr, code = run(["each", "abc", "--sep", ""])
+        assert code == 1
+        assert stdout == ""
+        assert stderr == "Error: empty separator\n"
+
+
+class TestGolden:
+    """Split results must be iterable, joinable and usable in an f-string."""
+
+    @pytest.mark.parametrize(
+        "argv,stdout",
+        [
+            (["underscore", "a_b_c"], "a b c\n"),
+            (["dash", "foo-bar-baz"], "foo bar baz\n"),
+            (["dot", "1.2.3"], "1 2 3\n"),
+            (["dot", "192.168.1.1"], "192 168 1 1\n"),
+            (["fmt", "a_b_c"], "a b c\n"),
+            (["each", "a.b.c.d.e"], "a\nb\nc\nd\ne\n"),
+            (["each", "x,y", "--sep", ","], "x\ny\n"),
+        ],
+    )
+    def test_golden(self, argv, stdout):
+        GOLDEN.check(argv, stdout)
+
+    def test_golden_wrong_count(self):
+        GOLDEN.check(["fmt", "a_b"], stdout="", returncode=1)
+
+    def test_golden_empty_sep(self):
+        GOLDEN.check(["each", "abc", "--sep", ""], stdout="", returncode=1)
@@ -0,0 +1,57 @@
+"""Tests for startswith_tool.py"""
+
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "startswith_tool.py"
+
+GOLDEN = GoldenCli(SCRIPT)
+run = GOLDEN.run
+
+
+class TestStarts:
+    def test_prefix(self):
+        stdout, _, code = run(["starts", "hello", "he"])
+        assert code == 0
+        assert stdout.strip() == "true"
+
+    def test_prefix_longer_than_text(self):
+        stdout, _, _ = run(["starts", "he", "hello"])
+        assert stdout.strip() == "false"
+
+    def test_mismatch(self):
+        stdout, _, _ = run(["starts", "hello", "hx"])
+        assert stdout.strip() == "false"
+
+
+class TestEnds:
+    def test_suffix(self):
+        stdout, _, _ = run(["ends", "hello", "lo"])
+        assert stdout.strip() == "true"
+
+    def test_suffix_uses_offset(self):
+        stdout, _, _ = run(["ends", "hello", "he"])
+        assert stdout.strip() == "false"
+
+    def test_suffix_longer_than_text(self):
+        stdout, _, _ = run(["ends", "lo", "hello"])
+        assert stdout.strip() == "false"
+
+
+class TestGolden:
+    """len(prefix) and len(text) are distinct expressions and must stay distinct"""
+
+    @pytest.mark.parametrize(
+        "argv,stdout",
+        [
+            (["starts", "hello", "he"], "true\n"),
+            (["starts", "he", "hello"], "false\n"),
+            (["ends", "hello", "lo"], "true\n"),
+            (["ends", "hello", "he"], "false\n"),
+        ],
+    )
+    def test_golden(self, argv, stdout):
+        GOLDEN.check(argv, stdout)
@@ -14,6 +14,8 @@ def main():
     lp.add_argument("text")
     t = subs.add_parser("title")
     t.add_argument("text")
+    sw = subs.add_parser("swapcase")
+    sw.add_argument("text")
     le = subs.add_parser("length")
     le.add_argument("text")
 
@@ -24,6 +26,8 @@ def main():
         print(args.text.lower())
     elif args.cmd == "title":
         print(args.text.title())
+    elif args.cmd == "swapcase":
+        print(args.text.swapcase())
     elif args.cmd == "length":
         print(len(args.text))
 
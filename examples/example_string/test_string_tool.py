@@ -0,0 +1,43 @@
+"""Tests for string_tool.py"""
+
+from pathlib import Path
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "string_tool.py"
+
+run = GoldenCli(SCRIPT).run
+
+
+class TestCaseMethods:
+    def test_upper(self):
+        stdout, _, _ = run(["upper", "Hello"])
+        assert stdout.strip() == "HELLO"
+
+    def test_lower(self):
+        stdout, _, _ = run(["lower", "Hello"])
+        assert stdout.strip() == "hello"
+
+    def test_title(self):
+        stdout, _, _ = run(["title", "hello world"])
+        assert stdout.strip() == "Hello World"
+
+
+class TestSwapcase:
+    def test_swapcase(self):
+        stdout, _, code = run(["swapcase", "Hello"])
+        assert code == 0
+        assert stdout.strip() == "hELLO"
+
+    def test_swapcase_keeps_uncased(self):
+        stdout, _, _ = run(["swapcase", "a1-B2"])
+        assert stdout.strip() == "A1-b2"
+
+    def test_swapcase_multibyte(self):
+        stdout, _, _ = run(["swapcase", "Éclair"])
+        assert stdout.strip() == "éCLAIR"
+
+    def test_swapcase_twice_is_identity(self):
+        stdout, _, _ = run(["swapcase", "MiXeD"])
+        stdout, _, _ = run(["swapcase", stdout.strip()])
+        assert stdout.strip() == "MiXeD"
@@ -1,5 +1,18 @@
 #!/usr/bin/env python3
-"""Strip Example - String strip operations CLI."""
+"""Strip Example - String strip operations CLI.
+
+With no argument, `strip`/`lstrip`/`rstrip` remove whitespace. With a
+string argument they remove any run of the characters it contains, in
+any order; the argument is a set of characters, not a prefix or suffix.
+
+Examples:
+    >>> "  hi  ".strip()
+    'hi'
+    >>> "__x__".lstrip("_")
+    'x__'
+    >>> "xyhixy".strip("yx")
+    'hi'
+"""
 
 import argparse
 
@@ -10,18 +23,33 @@ def main():
 
     b = subs.add_parser("both")
     b.add_argument("text")
+    b.add_argument("--chars", default="_")
     le = subs.add_parser("left")
     le.add_argument("text")
+    le.add_argument("--chars", default="_")
     r = subs.add_parser("right")
     r.add_argument("text")
+    r.add_argument("--chars", default="_")
+    t = subs.add_parser("trim")
+    t.add_argument("text")
+    lt = subs.add_parser("ltrim")
+    lt.add_argument("text")
+    rt = subs.add_parser("rtrim")
+    rt.add_argument("text")
 
     args = parser.parse_args()
     if args.cmd == "both":
-        print(args.text.strip("_"))
+        print(args.text.strip(args.chars))
     elif args.cmd == "left":
-        print(args.text.lstrip("_"))
+        print(args.text.lstrip(args.chars))
     elif args.cmd == "right":
-        print(args.text.rstrip("_"))
+        print(args.text.rstrip(args.chars))
+    elif args.cmd == "trim":
+        print(f"[{args.text.strip()}]")
+    elif args.cmd == "ltrim":
+        print(f"[{args.text.lstrip()}]")
+    elif args.cmd == "rtrim":
+        print(f"[{args.text.rstrip()}]")
 
 
 if __name__ == "__main__":
@@ -0,0 +1,80 @@
+"""Tests for strip_tool.py"""
+
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "strip_tool.py"
+
+GOLDEN = GoldenCli(SCRIPT)
+run = GOLDEN.run
+
+
+class TestWhitespace:
+    def test_trim(self):
+        stdout, _, code = run(["trim", "  hi  "])
+        assert code == 0
+        assert stdout == "[hi]\n"
+
+    def test_ltrim(self):
+        stdout, _, _ = run(["ltrim", "  hi  "])
+        assert stdout == "[hi  ]\n"
+
+    def test_rtrim(self):
+        stdout, _, _ = run(["rtrim", "  hi  "])
+        assert stdout == "[  hi]\n"
+
+    def test_tabs_and_newlines(self):
+        stdout, _, _ = run(["trim", "\t\n hi there \n"])
+        assert stdout == "[hi there]\n"
+
+    def test_all_whitespace(self):
+        stdout, _, _ = run(["trim", "   "])
+        assert stdout == "[]\n"
+
+
+class TestChars:
+    def test_default_underscore(self):
+        stdout, _, code = run(["both", "__x__"])
+        assert code == 0
+        assert stdout == "x\n"
+
+    def test_left(self):
+        stdout, _, _ = run(["left", "__x__"])
+        assert stdout == "x__\n"
+
+    def test_right(self):
+        stdout, _, _ = run(["right", "__x__"])
+        assert stdout == "__x\n"
+
+    def test_char_set_any_order(self):
+        stdout, _, _ = run(["both", "xyhixy", "--chars", "yx"])
+        assert stdout == "hi\n"
+
+    def test_inner_chars_kept(self):
+        stdout, _, _ = run(["both", "*a*b*", "--chars", "*"])
+        assert stdout == "a*b\n"
+
+    def test_whitespace_not_stripped_with_chars(self):
+        stdout, _, _ = run(["both", " _x_ "])
+        assert stdout == " _x_ \n"
+
+
+class TestGolden:
+    """strip() trims whitespace; strip(chars) trims any char in the set."""
+
+    @pytest.mark.parametrize(
+        "argv,stdout",
+        [
+            (["trim", "  hi  "], "[hi]\n"),
+            (["ltrim", "  hi  "], "[hi  ]\n"),
+            (["rtrim", "  hi  "], "[  hi]\n"),
+            (["both", "__x__"], "x\n"),
+            (["left", "__x__"], "x__\n"),
+            (["both", "xyhixy", "--chars", "yx"], "hi\n"),
+        ],
+    )
+    def test_golden(self, argv, stdout):
+        GOLDEN.check(argv, stdout)
@@ -8,9 +8,12 @@ Examples:
     24
     >>> average_three(3, 6, 9)
     6.0
+    >>> render(15, "json")
+    '{"result": 15}'
 """
 
 import argparse
+import json
 
 
 def sum_five(a: int, b: int, c: int, d: int, e: int) -> int:
@@ -53,8 +56,22 @@ def average_three(a: int, b: int, c: int) -> float:
     return total / 3
 
 
+def render(result: int | float, fmt: str) -> str:
+    """Format a result as plain text or as a JSON object.
+
+    >>> render(6.0, "text")
+    '6.0'
+    >>> render(6.0, "json")
+    '{"result": 6.0}'
+    """
+    if fmt == "json":
+        return json.dumps({"result": result})
+    return str(result)
+
+
 def main():
     parser = argparse.ArgumentParser(description="Sum tool")
+    parser.add_argument("--format", choices=["text", "json"], default="text")
     subs = parser.add_subparsers(dest="cmd", required=True)
 
     a = subs.add_parser("add")
@@ -74,11 +91,11 @@ def main():
 
     args = parser.parse_args()
     if args.cmd == "add":
-        print(sum_five(args.a, args.b, args.c, args.d, args.e))
+        print(render(sum_five(args.a, args.b, args.c, args.d, args.e), args.format))
     elif args.cmd == "product":
-        print(product_three(args.a, args.b, args.c))
+        print(render(product_three(args.a, args.b, args.c), args.format))
     elif args.cmd == "average":
-        print(average_three(args.a, args.b, args.c))
+        print(render(average_three(args.a, args.b, args.c), args.format))
 
 
 if __name__ == "__main__":
@@ -0,0 +1,40 @@
+"""Tests for sum_tool.py"""
+
+import json
+from pathlib import Path
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "sum_tool.py"
+
+run = GoldenCli(SCRIPT).run
+
+
+class TestText:
+    def test_default_is_text(self):
+        stdout, _, code = run(["add", "1", "2", "3", "4", "5"])
+        assert code == 0
+        assert stdout == "15\n"
+
+    def test_explicit_text(self):
+        stdout, _, _ = run(["--format", "text", "average", "3", "6", "9"])
+        assert stdout == "6.0\n"
+
+
+class TestJson:
+    def test_add(self):
+        stdout, _, code = run(["--format", "json", "add", "1", "2", "3", "4", "5"])
+        assert code == 0
+        assert json.loads(stdout) == {"result": 15}
+
+    def test_product(self):
+        stdout, _, _ = run(["--format", "json", "product", "2", "3", "4"])
+        assert stdout == '{"result": 24}\n'
+
+    def test_average_is_float(self):
+        stdout, _, _ = run(["--format", "json", "average", "1", "2", "4"])
+        assert json.loads(stdout)["result"] == 7 / 3
+
+    def test_unknown_format(self):
+        _, _, code = run(["--format", "yaml", "add", "1", "2", "3", "4", "5"])
+        assert code == 2
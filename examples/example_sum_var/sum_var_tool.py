@@ -0,0 +1,102 @@
+#!/usr/bin/env python3
+"""Sum Var Example - Variadic summation operations CLI.
+
+Variable-length counterpart of example_sum: each subcommand takes
+`nargs="*"` integers and a `list[int]` parameter instead of a fixed
+a..e, so any arity works. With no positional values the numbers are
+read whitespace-separated from stdin, e.g. `echo 1 2 3 | sum_var_tool.py add`.
+
+Examples:
+    >>> sum_all([1, 2, 3, 4, 5, 6])
+    21
+    >>> product_all([2, 3, 4])
+    24
+    >>> average_all([3, 6, 9, 12])
+    7.5
+"""
+
+import argparse
+import sys
+
+
+def sum_all(nums: list[int]) -> int:
+    """Sum of any number of integers.
+
+    >>> sum_all([7])
+    7
+    >>> sum_all([10, 20, 30, 40, 50])
+    150
+    """
+    total = 0
+    for n in nums:
+        total = total + n
+    return total
+
+
+def product_all(nums: list[int]) -> int:
+    """Product of any number of integers.
+
+    >>> product_all([1, 2, 3, 4, 5])
+    120
+    >>> product_all([0, 5])
+    0
+    """
+    result = 1
+    for n in nums:
+        result = result * n
+    return result
+
+
+def average_all(nums: list[int]) -> float:
+    """Average of any number of integers.
+
+    >>> average_all([1, 2])
+    1.5
+    """
+    return sum_all(nums) / len(nums)
+
+
+def read_numbers(text: str) -> list[int]:
+    """Parse whitespace-separated integers.
+
+    >>> read_numbers(" 1  2 3 ")
+    [1, 2, 3]
+    >>> read_numbers("")
+    []
+    """
+    return [int(tok) for tok in text.split()]
+
+
+def main():
+    parser = argparse.ArgumentParser(description="Variadic sum tool")
+    subs = parser.add_subparsers(dest="cmd", required=True)
+
+    a = subs.add_parser("add")
+    a.add_argument("nums", type=int, nargs="*")
+    p = subs.add_parser("product")
+    p.add_argument("nums", type=int, nargs="*")
+    av = subs.add_parser("average")
+    av.add_argument("nums", type=int, nargs="*")
+
+    args = parser.parse_args()
+    nums = args.nums
+    if not nums:
+        try:
+            nums = read_numbers(sys.stdin.read())
+        except ValueError as e:
+            print(f"Error: {e}", file=sys.stderr)
+            sys.exit(1)
+    if not nums:
+        print("Error: no numbers given", file=sys.stderr)
+        sys.exit(1)
+
+    if args.cmd == "add":
+        print(sum_all(nums))
+    elif args.cmd == "product":
+        print(product_all(nums))
+    elif args.cmd == "average":
+        print(average_all(nums))
+
+
+if __name__ == "__main__":
+    main()
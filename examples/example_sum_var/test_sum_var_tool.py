@@ -0,0 +1,66 @@
+"""Tests for sum_var_tool.py"""
+
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "sum_var_tool.py"
+
+run = GoldenCli(SCRIPT).run
+
+
+class TestAdd:
+    @pytest.mark.parametrize(
+        "nums,expected",
+        [
+            (["5"], "5"),
+            (["1", "2", "3"], "6"),
+            (["1", "2", "3", "4", "5", "6"], "21"),
+            ([str(n) for n in range(1, 101)], "5050"),
+        ],
+    )
+    def test_any_arity(self, nums, expected):
+        stdout, _, code = run(["add"] + nums)
+        assert code == 0
+        assert stdout.strip() == expected
+
+    def test_negative_values(self):
+        stdout, _, _ = run(["add", "--", "-1", "-2", "10"])
+        assert stdout.strip() == "7"
+
+    def test_no_numbers(self):
+        _, stderr, code = run(["add"])
+        assert code == 1
+        assert "Error: no numbers given" in stderr
+
+
+class TestProductAverage:
+    def test_product(self):
+        stdout, _, _ = run(["product", "1", "2", "3", "4", "5"])
+        assert stdout.strip() == "120"
+
+    def test_average(self):
+        stdout, _, _ = run(["average", "1", "2", "3", "4"])
+        assert stdout.strip() == "2.5"
+
+
+class TestStdin:
+    def test_piped_numbers(self):
+        stdout, _, code = run(["add"], stdin="1 2 3\n")
+        assert code == 0
+        assert stdout.strip() == "6"
+
+    def test_multiple_lines(self):
+        stdout, _, _ = run(["product"], stdin="2 3\n4\n")
+        assert stdout.strip() == "24"
+
+    def test_args_take_precedence(self):
+        stdout, _, _ = run(["add", "10"], stdin="1 2 3\n")
+        assert stdout.strip() == "10"
+
+    def test_bad_token(self):
+        _, stderr, code = run(["add"], stdin="1 two 3\n")
+        assert code == 1
+        assert "Error: invalid literal" in stderr
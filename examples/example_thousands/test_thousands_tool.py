@@ -0,0 +1,53 @@
+"""Tests for thousands_tool.py"""
+
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "thousands_tool.py"
+
+run = GoldenCli(SCRIPT).run
+
+
+# Every case runs through str.format and through the f-string
+STYLES = pytest.mark.parametrize("style", [[], ["--fstring"]])
+
+
+class TestGroup:
+    @STYLES
+    def test_large_int(self, style):
+        stdout, _, code = run(["group", "9876543210"] + style)
+        assert code == 0
+        assert stdout.strip() == "9,876,543,210"
+
+    @STYLES
+    def test_negative(self, style):
+        stdout, _, _ = run(["group", "-1234"] + style)
+        assert stdout.strip() == "-1,234"
+
+    @STYLES
+    def test_small(self, style):
+        stdout, _, _ = run(["group", "42"] + style)
+        assert stdout.strip() == "42"
+
+
+class TestRatio:
+    @STYLES
+    def test_one_decimal(self, style):
+        stdout, _, code = run(["ratio", "2", "3"] + style)
+        assert code == 0
+        assert stdout.strip() == "66.7%"
+
+    @STYLES
+    def test_whole(self, style):
+        stdout, _, _ = run(["ratio", "1", "4"] + style)
+        assert stdout.strip() == "25.0%"
+
+    @STYLES
+    def test_zero_total(self, style):
+        stdout, stderr, code = run(["ratio", "1", "0"] + style)
+        assert code == 1
+        assert stdout == ""
+        assert stderr == "Error: total must not be zero\n"
@@ -0,0 +1,82 @@
+#!/usr/bin/env python3
+"""Thousands Example - Grouping and percent format specs CLI.
+
+`,` groups digits in threes and `%` multiplies by 100 and appends a
+percent sign. Both specs behave the same in `str.format` and f-strings.
+
+Examples:
+    >>> group_format(1234567)
+    '1,234,567'
+    >>> group_fstring(1234567)
+    '1,234,567'
+    >>> ratio_format(1, 3)
+    '33.3%'
+    >>> ratio_fstring(1, 3)
+    '33.3%'
+"""
+
+import argparse
+import sys
+
+
+def group_format(n: int) -> str:
+    """Digit grouping via str.format.
+
+    >>> group_format(-1000)
+    '-1,000'
+    >>> group_format(999)
+    '999'
+    """
+    return "{:,}".format(n)
+
+
+def group_fstring(n: int) -> str:
+    """Digit grouping via an f-string."""
+    return f"{n:,}"
+
+
+def ratio_format(part: int, total: int) -> str:
+    """Percentage with one decimal via str.format.
+
+    >>> ratio_format(1, 8)
+    '12.5%'
+    """
+    return "{:.1%}".format(part / total)
+
+
+def ratio_fstring(part: int, total: int) -> str:
+    """Percentage with one decimal via an f-string.
+
+    >>> ratio_fstring(2, 2)
+    '100.0%'
+    """
+    return f"{part / total:.1%}"
+
+
+def main():
+    parser = argparse.ArgumentParser(description="Number grouping tool")
+    subs = parser.add_subparsers(dest="cmd", required=True)
+
+    g = subs.add_parser("group")
+    g.add_argument("n", type=int)
+    g.add_argument("--fstring", action="store_true")
+    r = subs.add_parser("ratio")
+    r.add_argument("part", type=int)
+    r.add_argument("total", type=int)
+    r.add_argument("--fstring", action="store_true")
+
+    args = parser.parse_args()
+    if args.cmd == "group":
+        print(group_fstring(args.n) if args.fstring else group_format(args.n))
+    elif args.cmd == "ratio":
+        if args.total == 0:
+            print("Error: total must not be zero", file=sys.stderr)
+            sys.exit(1)
+        if args.fstring:
+            print(ratio_fstring(args.part, args.total))
+        else:
+            print(ratio_format(args.part, args.total))
+
+
+if __name__ == "__main__":
+    main()
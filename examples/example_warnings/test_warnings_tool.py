@@ -0,0 +1,38 @@
+"""Tests for warnings_tool.py"""
+
+from pathlib import Path
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "warnings_tool.py"
+
+run = GoldenCli(SCRIPT).run
+
+
+class TestUserWarning:
+    def test_no_warning_in_range(self):
+        stdout, stderr, code = run(["percent", "42"])
+        assert code == 0
+        assert stdout.strip() == "42"
+        assert "Warning" not in stderr
+
+    def test_warning_on_stderr(self):
+        stdout, stderr, code = run(["percent", "150"])
+        assert code == 0
+        assert stdout.strip() == "100"
+        assert "Warning" in stderr
+        assert "value 150 clamped to 100" in stderr
+
+    def test_warning_not_on_stdout(self):
+        stdout, _, _ = run(["percent", "-5"])
+        assert "clamped" not in stdout
+        assert stdout.strip() == "0"
+
+
+class TestDeprecationWarning:
+    def test_deprecation_on_stderr(self):
+        stdout, stderr, code = run(["scale-old", "2"])
+        assert code == 0
+        assert stdout.strip() == "200"
+        assert "DeprecationWarning" in stderr
+        assert "scale-old is deprecated" in stderr
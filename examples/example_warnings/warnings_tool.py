@@ -0,0 +1,57 @@
+#!/usr/bin/env python3
+"""Warnings Example - Deprecation and user warnings CLI.
+
+Warnings go to stderr and never interrupt the command's stdout result.
+
+Examples:
+    >>> clamp_percent(50)
+    50
+    >>> clamp_percent(7)
+    7
+"""
+
+import argparse
+import warnings
+
+
+def clamp_percent(value: int) -> int:
+    """Clamp a percentage into 0..100, warning when it had to be clamped.
+
+    >>> clamp_percent(0)
+    0
+    >>> clamp_percent(100)
+    100
+    """
+    if value > 100:
+        warnings.warn(f"value {value} clamped to 100")
+        return 100
+    if value < 0:
+        warnings.warn(f"value {value} clamped to 0")
+        return 0
+    return value
+
+
+def legacy_scale(value: int) -> int:
+    """Old scaling entry point kept for compatibility."""
+    warnings.warn("scale-old is deprecated, use percent", DeprecationWarning)
+    return value * 100
+
+
+def main():
+    parser = argparse.ArgumentParser(description="Warnings tool")
+    subs = parser.add_subparsers(dest="cmd", required=True)
+
+    p = subs.add_parser("percent")
+    p.add_argument("value", type=int)
+    s = subs.add_parser("scale-old")
+    s.add_argument("value", type=int)
+
+    args = parser.parse_args()
+    if args.cmd == "percent":
+        print(clamp_percent(args.value))
+    elif args.cmd == "scale-old":
+        print(legacy_scale(args.value))
+
+
+if __name__ == "__main__":
+    main()
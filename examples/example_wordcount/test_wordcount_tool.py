@@ -0,0 +1,81 @@
+"""Tests for wordcount_tool.py"""
+
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "wordcount_tool.py"
+
+GOLDEN = GoldenCli(SCRIPT)
+run = GOLDEN.run
+
+TEXT = "the quick brown fox\njumps over the lazy dog\nThe End\n"
+
+
+class TestAll:
+    def test_sorted_by_word(self):
+        stdout, _, code = run(["all"], TEXT)
+        assert code == 0
+        assert stdout.splitlines() == [
+            "brown 1",
+            "dog 1",
+            "end 1",
+            "fox 1",
+            "jumps 1",
+            "lazy 1",
+            "over 1",
+            "quick 1",
+            "the 3",
+        ]
+
+    def test_empty(self):
+        stdout, _, code = run(["all"], "")
+        assert code == 0
+        assert stdout == ""
+
+    def test_whitespace_runs(self):
+        stdout, _, _ = run(["all"], "  a \t a\n\n b ")
+        assert stdout.splitlines() == ["a 2", "b 1"]
+
+
+class TestTop:
+    def test_top(self):
+        stdout, _, _ = run(["top", "2"], "b a b c a b")
+        assert stdout.splitlines() == ["b 3", "a 2"]
+
+    def test_ties_alphabetical(self):
+        stdout, _, _ = run(["top", "3"], "z y x")
+        assert stdout.splitlines() == ["x 1", "y 1", "z 1"]
+
+    def test_more_than_available(self):
+        stdout, _, _ = run(["top", "10"], "a a")
+        assert stdout.splitlines() == ["a 2"]
+
+
+class TestGet:
+    def test_present_case_insensitive(self):
+        stdout, _, _ = run(["get", "THE"], TEXT)
+        assert stdout.strip() == "3"
+
+    def test_missing_defaults_to_zero(self):
+        stdout, _, _ = run(["get", "cat"], TEXT)
+        assert stdout.strip() == "0"
+
+
+class TestGolden:
+    """Dict tally, .get default and sorted .items() must match Python."""
+
+    @pytest.mark.parametrize(
+        "argv,stdin,stdout",
+        [
+            (["all"], "b a b\n", "a 1\nb 2\n"),
+            (["top", "2"], "b a b c a b", "b 3\na 2\n"),
+            (["get", "the"], TEXT, "3\n"),
+            (["get", "cat"], TEXT, "0\n"),
+            (["all"], "", ""),
+        ],
+    )
+    def test_golden(self, argv, stdin, stdout):
+        GOLDEN.check(argv, stdout, stdin=stdin)
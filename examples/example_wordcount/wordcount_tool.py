@@ -0,0 +1,71 @@
+#!/usr/bin/env python3
+"""Wordcount Example - Word frequency tally CLI.
+
+Reads stdin, lowercases it and splits on whitespace, then tallies words
+in a dict. Output is sorted, so it does not depend on insertion order.
+
+Examples:
+    >>> tally("the cat the hat")
+    {'the': 2, 'cat': 1, 'hat': 1}
+    >>> top(tally("b a b c a b"), 2)
+    [('b', 3), ('a', 2)]
+"""
+
+import argparse
+import sys
+
+
+def tally(text: str) -> dict[str, int]:
+    """Count each lowercased word.
+
+    >>> tally("")
+    {}
+    >>> tally("Go go GO")
+    {'go': 3}
+    """
+    counts = {}
+    for word in text.lower().split():
+        if word in counts:
+            counts[word] = counts[word] + 1
+        else:
+            counts[word] = 1
+    return counts
+
+
+def top(counts: dict[str, int], n: int) -> list[tuple[str, int]]:
+    """The n most frequent words, ties broken alphabetically.
+
+    >>> top({"b": 1, "a": 1}, 5)
+    [('a', 1), ('b', 1)]
+    """
+    pairs = []
+    for word, count in counts.items():
+        pairs.append((word, count))
+    pairs.sort(key=lambda p: (-p[1], p[0]))
+    return pairs[:n]
+
+
+def main():
+    parser = argparse.ArgumentParser(description="Word frequency tool")
+    subs = parser.add_subparsers(dest="cmd", required=True)
+
+    subs.add_parser("all")
+    t = subs.add_parser("top")
+    t.add_argument("n", type=int)
+    g = subs.add_parser("get")
+    g.add_argument("word")
+
+    args = parser.parse_args()
+    counts = tally(sys.stdin.read())
+    if args.cmd == "all":
+        for word, count in sorted(counts.items()):
+            print(f"{word} {count}")
+    elif args.cmd == "top":
+        for word, count in top(counts, args.n):
+            print(f"{word} {count}")
+    elif args.cmd == "get":
+        print(counts.get(args.word.lower(), 0))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,43 @@
+"""Tests for zip_tool.py"""
+
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "zip_tool.py"
+
+GOLDEN = GoldenCli(SCRIPT)
+run = GOLDEN.run
+
+
+class TestPair:
+    def test_pair(self):
+        stdout, _, code = run(["pair", "x", "y", "z", "p", "q", "r"])
+        assert code == 0
+        assert stdout.strip() == "x:p y:q z:r"
+
+
+class TestArithmetic:
+    def test_sum(self):
+        stdout, _, _ = run(["sum", "1", "2", "3", "10", "20", "30"])
+        assert stdout.strip() == "11 22 33"
+
+    def test_diff(self):
+        stdout, _, _ = run(["diff", "5", "5", "5", "1", "2", "3"])
+        assert stdout.strip() == "4 3 2"
+
+
+class TestGolden:
+    """Every subcommand arm must bind the fields its body reads"""
+
+    @pytest.mark.parametrize(
+        "argv,stdout",
+        [
+            (["pair", "x", "y", "z", "p", "q", "r"], "x:p y:q z:r\n"),
+            (["sum", "1", "2", "3", "4", "5", "6"], "5 7 9\n"),
+        ],
+    )
+    def test_golden(self, argv, stdout):
+        GOLDEN.check(argv, stdout)
@@ -0,0 +1,40 @@
+"""Tests for zip_strict_tool.py"""
+
+from pathlib import Path
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "zip_strict_tool.py"
+
+run = GoldenCli(SCRIPT).run
+
+
+class TestLoose:
+    def test_equal_lengths(self):
+        stdout, _, code = run(["loose", "a,b,c", "x,y,z"])
+        assert code == 0
+        assert stdout.strip() == "a:x b:y c:z"
+
+    def test_truncates(self):
+        stdout, _, code = run(["loose", "a,b,c", "x"])
+        assert code == 0
+        assert stdout.strip() == "a:x"
+
+
+class TestStrict:
+    def test_equal_lengths(self):
+        stdout, _, code = run(["strict", "a,b", "x,y"])
+        assert code == 0
+        assert stdout.strip() == "a:x b:y"
+
+    def test_left_longer_raises(self):
+        stdout, stderr, code = run(["strict", "a,b,c", "x,y"])
+        assert code == 1
+        assert stdout == ""
+        assert "argument 2 is shorter than argument 1" in stderr
+
+    def test_right_longer_raises(self):
+        stdout, stderr, code = run(["strict", "a", "x,y"])
+        assert code == 1
+        assert stdout == ""
+        assert "argument 2 is longer than argument 1" in stderr
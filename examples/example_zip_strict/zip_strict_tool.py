@@ -0,0 +1,63 @@
+#!/usr/bin/env python3
+"""Zip Strict Example - zip(strict=True) length checking CLI.
+
+Plain `zip` silently truncates to the shorter input; `strict=True`
+raises ValueError when the lengths differ.
+
+Examples:
+    >>> pair_loose(["a", "b", "c"], ["x", "y"])
+    ['a:x', 'b:y']
+    >>> pair_strict(["a", "b"], ["x", "y"])
+    ['a:x', 'b:y']
+"""
+
+import argparse
+import sys
+
+
+def pair_loose(left: list[str], right: list[str]) -> list[str]:
+    """Pair items, truncating to the shorter list.
+
+    >>> pair_loose([], ["x"])
+    []
+    """
+    return [f"{a}:{b}" for a, b in zip(left, right, strict=False)]
+
+
+def pair_strict(left: list[str], right: list[str]) -> list[str]:
+    """Pair items, raising ValueError on a length mismatch.
+
+    >>> pair_strict(["a"], ["x", "y"])
+    Traceback (most recent call last):
+        ...
+    ValueError: zip() argument 2 is longer than argument 1
+    """
+    return [f"{a}:{b}" for a, b in zip(left, right, strict=True)]
+
+
+def main():
+    parser = argparse.ArgumentParser(description="Strict zip tool")
+    subs = parser.add_subparsers(dest="cmd", required=True)
+
+    lo = subs.add_parser("loose")
+    lo.add_argument("left")
+    lo.add_argument("right")
+    st = subs.add_parser("strict")
+    st.add_argument("left")
+    st.add_argument("right")
+
+    args = parser.parse_args()
+    left = args.left.split(",")
+    right = args.right.split(",")
+    if args.cmd == "loose":
+        print(" ".join(pair_loose(left, right)))
+    elif args.cmd == "strict":
+        try:
+            print(" ".join(pair_strict(left, right)))
+        except ValueError as e:
+            print(f"Error: {e}", file=sys.stderr)
+            sys.exit(1)
+
+
+if __name__ == "__main__":
+    main()
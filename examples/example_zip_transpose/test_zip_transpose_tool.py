@@ -0,0 +1,30 @@
+"""Tests for zip_transpose_tool.py"""
+
+from pathlib import Path
+
+from reprorusted_python_cli.golden import GoldenCli
+
+SCRIPT = Path(__file__).parent / "zip_transpose_tool.py"
+
+run = GoldenCli(SCRIPT).run
+
+
+class TestTranspose:
+    def test_2x3(self):
+        stdout, _, code = run(["transpose", "1,2,3", "4,5,6"])
+        assert code == 0
+        assert stdout == "1 4\n2 5\n3 6\n"
+
+    def test_round_trip(self):
+        stdout, _, _ = run(["transpose", "1,4", "2,5", "3,6"])
+        assert stdout == "1 2 3\n4 5 6\n"
+
+    def test_ragged_rows_truncate(self):
+        stdout, _, _ = run(["transpose", "1,2,3", "4,5"])
+        assert stdout == "1 4\n2 5\n"
+
+
+class TestColumnSums:
+    def test_colsum(self):
+        stdout, _, _ = run(["colsum", "1,2,3", "4,5,6"])
+        assert stdout.strip() == "5 7 9"
@@ -0,0 +1,66 @@
+#!/usr/bin/env python3
+"""Zip Transpose Example - zip(*rows) matrix transpose CLI.
+
+Star-unpacking a list of rows into `zip` pairs up the i-th element of
+every row, which turns rows into columns. Each column comes back as a
+tuple, so the result is converted to a list of lists.
+
+Examples:
+    >>> transpose([[1, 2, 3], [4, 5, 6]])
+    [[1, 4], [2, 5], [3, 6]]
+    >>> column_sums([[1, 2, 3], [4, 5, 6]])
+    [5, 7, 9]
+"""
+
+import argparse
+
+
+def parse_rows(rows: list[str]) -> list[list[int]]:
+    """Parse comma-separated rows into a matrix.
+
+    >>> parse_rows(["1,2", "3,4"])
+    [[1, 2], [3, 4]]
+    """
+    return [[int(x) for x in row.split(",")] for row in rows]
+
+
+def transpose(matrix: list[list[int]]) -> list[list[int]]:
+    """Swap rows and columns.
+
+    >>> transpose([[1], [2]])
+    [[1, 2]]
+    >>> transpose([])
+    []
+    """
+    return [list(col) for col in zip(*matrix)]
+
+
+def column_sums(matrix: list[list[int]]) -> list[int]:
+    """Sum each column.
+
+    >>> column_sums([[1, 1], [2, 2], [3, 3]])
+    [6, 6]
+    """
+    return [sum(col) for col in zip(*matrix)]
+
+
+def main():
+    parser = argparse.ArgumentParser(description="Matrix transpose tool")
+    subs = parser.add_subparsers(dest="cmd", required=True)
+
+    t = subs.add_parser("transpose")
+    t.add_argument("rows", nargs="+")
+    s = subs.add_parser("colsum")
+    s.add_argument("rows", nargs="+")
+
+    args = parser.parse_args()
+    matrix = parse_rows(args.rows)
+    if args.cmd == "transpose":
+        for row in transpose(matrix):
+            print(" ".join(str(x) for x in row))
+    elif args.cmd == "colsum":
+        print(" ".join(str(x) for x in column_sums(matrix)))
+
+
+if __name__ == "__main__":
+    main()
@@ -1,13 +1,38 @@
 #!/usr/bin/python3
-"""Re-transpile corpus with latest depyler and enrich with insights."""
+"""Re-transpile corpus with latest depyler and enrich with insights.
 
+Usage:
+    python scripts/retranspile_corpus.py [--no-cache] [--jobs N]
+"""
+
+import argparse
 import json
+import os
 import subprocess
 import sys
+from concurrent.futures import ThreadPoolExecutor
 from pathlib import Path
 import pyarrow as pa
 import pyarrow.parquet as pq
 
+# One import path only, so the cache module is never loaded twice
+if __package__:
+    from .transpile_cache import (
+        RUN_FAILED,
+        TIMEOUT,
+        TranspileCache,
+        depyler_fingerprint,
+        transpile_cached,
+    )
+else:
+    from transpile_cache import (
+        RUN_FAILED,
+        TIMEOUT,
+        TranspileCache,
+        depyler_fingerprint,
+        transpile_cached,
+    )
+
 # Tarantula suspiciousness scores
 TARANTULA_SCORES = {
     "async_await": 0.946,
@@ -69,11 +94,46 @@ def transpile_python(python_path: Path, depyler_bin: Path) -> tuple[str | None,
             return result.stdout, None
         return None, result.stderr
     except subprocess.TimeoutExpired:
-        return None, "timeout"
+        return None, TIMEOUT
     except Exception as e:
-        return None, str(e)
+        return None, f"{RUN_FAILED}{e}"
+
+def collect_python_files(examples_dir: Path) -> list[Path]:
+    """All Python files under examples/example_*, in sorted order."""
+    python_files = []
+    for example_dir in sorted(examples_dir.iterdir()):
+        if not example_dir.is_dir() or not example_dir.name.startswith("example_"):
+            continue
+        python_files.extend(sorted(example_dir.glob("*.py")))
+    return python_files
+
+def transpile_all(
+    python_files: list[Path],
+    depyler_bin: Path,
+    cache: TranspileCache | None = None,
+    jobs: int = 1,
+    transpile=transpile_python,
+) -> list[tuple[str | None, str | None]]:
+    """Transpile files concurrently; results are in the same order as python_files."""
+    def work(python_file: Path) -> tuple[str | None, str | None]:
+        return transpile_cached(
+            python_file, python_file.read_text(), depyler_bin, transpile, cache
+        )
+
+    with ThreadPoolExecutor(max_workers=max(1, jobs)) as pool:
+        return list(pool.map(work, python_files))
 
 def main():
+    parser = argparse.ArgumentParser(description="Re-transpile corpus with depyler")
+    parser.add_argument(
+        "--no-cache", action="store_true", help="Re-transpile every file, ignoring the cache"
+    )
+    parser.add_argument(
+        "-j", "--jobs", type=int, default=os.cpu_count() or 1,
+        help="Number of files to transpile concurrently",
+    )
+    args = parser.parse_args()
+
     project_root = Path(__file__).parent.parent
     examples_dir = project_root / "examples"
     depyler_bin = Path.home() / "src" / "depyler" / "target" / "release" / "depyler"
@@ -84,6 +144,11 @@ def main():
 
     print(f"Using depyler: {depyler_bin}")
 
+    cache = None
+    if not args.no_cache:
+        cache_path = project_root / "data" / "transpile_cache.json"
+        cache = TranspileCache.load(cache_path, depyler_fingerprint(depyler_bin))
+
     # Collect all examples
     records = []
     total = 0
@@ -95,59 +160,53 @@ def main():
     existing_df = pq.read_table(existing_path).to_pandas()
     existing_rust = set(existing_df[existing_df['has_rust']]['category'].tolist())
 
-    for example_dir in sorted(examples_dir.iterdir()):
-        if not example_dir.is_dir() or not example_dir.name.startswith("example_"):
-            continue
-
-        # Find ALL Python files
-        python_files = list(example_dir.glob("*.py"))
-        if not python_files:
-            continue
+    python_files = collect_python_files(examples_dir)
+    results = transpile_all(python_files, depyler_bin, cache, args.jobs)
 
+    # Aggregate in file order so the report is identical for any --jobs value
+    for python_file, (rust_code, error) in zip(python_files, results):
+        example_dir = python_file.parent
         category = example_dir.name.replace("example_", "")
-
-        # Process each Python file in the example
-        for python_file in sorted(python_files):
-            python_code = python_file.read_text()
-
-            total += 1
-
-            # Transpile
-            rust_code, error = transpile_python(python_file, depyler_bin)
-            has_rust = rust_code is not None and len(rust_code.strip()) > 0
-
-            if has_rust:
-                success += 1
-                if category not in existing_rust:
-                    improved += 1
-                    print(f"  NEW: {category} ({python_file.name})")
-
-            # Detect features and compute suspiciousness
-            features = detect_features(python_code)
-            suspiciousness = compute_suspiciousness(features)
-
-            records.append({
-                "example_name": example_dir.name,
-                "python_file": python_file.name,
-                "python_code": python_code,
-                "rust_code": rust_code or "",
-                "has_rust": has_rust,
-                "category": category,
-                "python_lines": len(python_code.splitlines()),
-                "rust_lines": len(rust_code.splitlines()) if rust_code else 0,
-                "blocking_features": features,
-                "suspiciousness": suspiciousness,
-                "error": error[:500] if error else None,
-            })
-
-            if total % 50 == 0:
-                print(f"  Processed {total}...")
+        python_code = python_file.read_text()
+
+        total += 1
+        has_rust = rust_code is not None and len(rust_code.strip()) > 0
+
+        if has_rust:
+            success += 1
+            if category not in existing_rust:
+                improved += 1
+                print(f"  NEW: {category} ({python_file.name})")
+
+        # Detect features and compute suspiciousness
+        features = detect_features(python_code)
+        suspiciousness = compute_suspiciousness(features)
+
+        records.append({
+            "example_name": example_dir.name,
+            "python_file": python_file.name,
+            "python_code": python_code,
+            "rust_code": rust_code or "",
+            "has_rust": has_rust,
+            "category": category,
+            "python_lines": len(python_code.splitlines()),
+            "rust_lines": len(rust_code.splitlines()) if rust_code else 0,
+            "blocking_features": features,
+            "suspiciousness": suspiciousness,
+            "error": error[:500] if error else None,
+        })
+
+        if total % 50 == 0:
+            print(f"  Processed {total}...")
 
     print(f"\nResults:")
     print(f"  Total: {total}")
     print(f"  Success: {success} ({100*success/total:.1f}%)")
     print(f"  Previous: {len(existing_rust)}")
     print(f"  Improved: {improved}")
+    if cache is not None:
+        cache.save()
+        print(f"  Cache: {cache.hits} hits, {cache.misses} misses")
 
     # Create Arrow table
     schema = pa.schema([
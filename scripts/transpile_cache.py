@@ -0,0 +1,117 @@
+#!/usr/bin/env python3
+"""Content-hash cache for corpus transpilation.
+
+Maps the SHA-256 of a Python source to depyler's previous result so
+unchanged files skip re-transpilation. The whole cache is tied to a
+fingerprint of the depyler binary and is discarded when depyler changes.
+
+Usage:
+    cache = TranspileCache.load(path, depyler_fingerprint(depyler_bin))
+    rust_code, error = transpile_cached(python_file, code, depyler_bin, transpile, cache)
+    cache.save()
+"""
+
+import hashlib
+import json
+import threading
+from collections.abc import Callable
+from dataclasses import dataclass, field
+from pathlib import Path
+
+CACHE_VERSION = 1
+
+TranspileResult = tuple[str | None, str | None]
+
+# Errors from running depyler rather than from depyler itself; never cached
+TIMEOUT = "timeout"
+RUN_FAILED = "run failed: "
+
+
+def is_transient(error: str | None) -> bool:
+    """Whether error says nothing about the source, e.g. a timeout or spawn failure."""
+    return error is not None and (error == TIMEOUT or error.startswith(RUN_FAILED))
+
+
+def source_hash(code: str) -> str:
+    """SHA-256 hex digest of a Python source."""
+    return hashlib.sha256(code.encode("utf-8")).hexdigest()
+
+
+def depyler_fingerprint(depyler_bin: Path) -> str:
+    """Identify a depyler build by path, size and modification time."""
+    st = depyler_bin.stat()
+    return f"{depyler_bin.resolve()}:{st.st_size}:{st.st_mtime_ns}"
+
+
+@dataclass
+class TranspileCache:
+    """Source-hash keyed transpilation results for one depyler build."""
+
+    path: Path
+    fingerprint: str
+    entries: dict[str, dict] = field(default_factory=dict)
+    hits: int = 0
+    misses: int = 0
+    _lock: threading.Lock = field(default_factory=threading.Lock, repr=False, compare=False)
+
+    @classmethod
+    def load(cls, path: Path, fingerprint: str) -> "TranspileCache":
+        """Load a cache file, starting empty if it is missing, corrupt or stale."""
+        cache = cls(path, fingerprint)
+        try:
+            data = json.loads(path.read_text())
+        except (OSError, ValueError):
+            return cache
+        if data.get("version") == CACHE_VERSION and data.get("fingerprint") == fingerprint:
+            cache.entries = data.get("entries", {})
+        return cache
+
+    def get(self, code: str) -> TranspileResult | None:
+        """Cached (rust_code, error) for this source, counting hits and misses."""
+        key = source_hash(code)
+        with self._lock:
+            entry = self.entries.get(key)
+            if entry is None:
+                self.misses += 1
+                return None
+            self.hits += 1
+            return entry["rust_code"], entry["error"]
+
+    def put(self, code: str, rust_code: str | None, error: str | None) -> None:
+        """Record a transpilation result."""
+        key = source_hash(code)
+        with self._lock:
+            self.entries[key] = {"rust_code": rust_code, "error": error}
+
+    def save(self) -> None:
+        """Write the cache to disk."""
+        self.path.parent.mkdir(parents=True, exist_ok=True)
+        with self._lock:
+            payload = json.dumps({
+                "version": CACHE_VERSION,
+                "fingerprint": self.fingerprint,
+                "entries": self.entries,
+            })
+        self.path.write_text(payload)
+
+
+def transpile_cached(
+    python_path: Path,
+    code: str,
+    depyler_bin: Path,
+    transpile: Callable[[Path, Path], TranspileResult],
+    cache: TranspileCache | None,
+) -> TranspileResult:
+    """Transpile through the cache; a None cache always runs depyler.
+
+    Only depyler's own verdicts are stored. Transient failures are retried
+    on the next run.
+    """
+    if cache is not None:
+        cached = cache.get(code)
+        if cached is not None:
+            return cached
+    rust_code, error = transpile(python_path, depyler_bin)
+    if cache is not None and not is_transient(error):
+        cache.put(code, rust_code, error)
+    return rust_code, error
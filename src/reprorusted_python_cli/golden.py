@@ -0,0 +1,273 @@
+"""Golden end-to-end checks for transpiled CLIs.
+
+A golden case pins the stdout and exit code of one CLI invocation. The
+Python source is always run; when depyler is available the source is also
+compiled to a Rust binary and run with the same argv, so one case checks
+both sides of the transpilation. If depyler's `compile --help` does not list
+a selected codegen flag, only the Python side is checked.
+
+Usage:
+    cli = GoldenCli(Path(__file__).parent / "trivial_cli.py")
+    cli.check(["--name", "Alice"], stdout="Hello, Alice!\\n")
+    stdout, stderr, code = cli.run(["--name", "Alice"])
+"""
+
+import os
+import re
+import shutil
+import subprocess
+import tempfile
+from dataclasses import dataclass
+from pathlib import Path
+
+# Environment variable pointing at a specific depyler binary
+DEPYLER_ENV = "DEPYLER_BIN"
+
+# Environment variable selecting the Rust edition depyler compiles for
+EDITION_ENV = "DEPYLER_EDITION"
+
+RUST_EDITIONS = ("2015", "2018", "2021", "2024")
+
+# Environment variable selecting how depyler maps Python int
+INT_MODE_ENV = "DEPYLER_INT"
+
+INT_MODES = ("native", "bigint")
+
+# Environment variable selecting how depyler reports Python exceptions
+ERROR_MODE_ENV = "DEPYLER_ERRORS"
+
+ERROR_MODES = ("exceptions", "anyhow")
+
+# Environment variable selecting how depyler handles out-of-range indices
+BOUNDS_ENV = "DEPYLER_BOUNDS"
+
+BOUNDS_MODES = ("default", "checked")
+
+# (attribute, environment variable, allowed values, depyler flag) per codegen option
+CODEGEN_OPTIONS = (
+    ("edition", EDITION_ENV, RUST_EDITIONS, "--target-edition"),
+    ("int_mode", INT_MODE_ENV, INT_MODES, "--int"),
+    ("errors", ERROR_MODE_ENV, ERROR_MODES, "--errors"),
+    ("bounds", BOUNDS_ENV, BOUNDS_MODES, "--bounds"),
+)
+
+RUN_TIMEOUT = 30
+COMPILE_TIMEOUT = 300
+
+
+@dataclass
+class RunResult:
+    """Captured output of one CLI invocation."""
+
+    stdout: str
+    stderr: str
+    returncode: int
+
+
+class GoldenMismatch(AssertionError):
+    """A backend's output differed from the golden expectation."""
+
+
+def find_depyler() -> str | None:
+    """Locate depyler via $DEPYLER_BIN, falling back to PATH."""
+    override = os.environ.get(DEPYLER_ENV)
+    if override:
+        return override if Path(override).is_file() else None
+    return shutil.which("depyler")
+
+
+_help_cache: dict[str, str] = {}
+
+
+def compile_help(depyler: str) -> str:
+    """Output of `depyler compile --help`, cached per binary; empty if it fails."""
+    if depyler not in _help_cache:
+        try:
+            result = subprocess.run(
+                [depyler, "compile", "--help"],
+                capture_output=True,
+                text=True,
+                timeout=RUN_TIMEOUT,
+            )
+            text = result.stdout + result.stderr if result.returncode == 0 else ""
+        except (OSError, subprocess.TimeoutExpired):
+            text = ""
+        _help_cache[depyler] = text
+    return _help_cache[depyler]
+
+
+def _lists_flag(help_text: str, flag: str) -> bool:
+    """Whether help_text mentions the option name of a `--name=value` flag."""
+    name = flag.split("=")[0]
+    return re.search(rf"(?<![\w-]){re.escape(name)}(?![\w-])", help_text) is not None
+
+
+def _run(cmd: list[str], stdin: str | None) -> RunResult:
+    result = subprocess.run(
+        cmd, capture_output=True, text=True, input=stdin, timeout=RUN_TIMEOUT
+    )
+    return RunResult(result.stdout, result.stderr, result.returncode)
+
+
+class GoldenCli:
+    """One Python CLI source checked against golden argv/stdout cases."""
+
+    def __init__(
+        self,
+        source: Path,
+        depyler: str | None = None,
+        edition: str | None = None,
+        int_mode: str | None = None,
+        errors: str | None = None,
+        bounds: str | None = None,
+    ):
+        self.source = Path(source)
+        self.depyler = depyler or find_depyler()
+        selected = {"edition": edition, "int_mode": int_mode, "errors": errors, "bounds": bounds}
+        for attr, env, allowed, _ in CODEGEN_OPTIONS:
+            value = selected[attr] or os.environ.get(env) or None
+            if value is not None and value not in allowed:
+                raise ValueError(f"unknown {attr} {value!r}, expected one of {allowed}")
+            setattr(self, attr, value)
+        self._binary: Path | None = None
+        self._build_dir: tempfile.TemporaryDirectory | None = None
+
+    @property
+    def has_rust(self) -> bool:
+        """Whether the Rust side can be checked with the selected flags."""
+        return self.depyler is not None and not self.unsupported_flags()
+
+    @property
+    def skip_reason(self) -> str:
+        """Why the Rust side is not checked, for pytest.skip."""
+        if self.depyler is None:
+            return "depyler not available"
+        return f"depyler does not support {' '.join(self.unsupported_flags())}"
+
+    def supports(self, flag: str) -> bool:
+        """Whether `depyler compile --help` lists flag (with or without `=value`)."""
+        return self.depyler is not None and _lists_flag(compile_help(self.depyler), flag)
+
+    def unsupported_flags(self) -> list[str]:
+        """Codegen flags that `depyler compile --help` does not list."""
+        if self.depyler is None:
+            return []
+        return [flag for flag in self.codegen_flags() if not self.supports(flag)]
+
+    def run_python(self, argv: list[str], stdin: str | None = None) -> RunResult:
+        """Run the Python source with argv."""
+        return _run(["python3", str(self.source), *argv], stdin)
+
+    def run(self, argv: list[str], stdin: str = "") -> tuple[str, str, int]:
+        """Run the Python source and return (stdout, stderr, returncode).
+
+        stdin defaults to empty, so tools that read it never block.
+        """
+        result = self.run_python(argv, stdin)
+        return result.stdout, result.stderr, result.returncode
+
+    def codegen_flags(self) -> list[str]:
+        """depyler flags for the selected edition and int, error and bounds modes.
+
+        Every flag is passed as `--name=value`. With an edition set, depyler
+        is asked to emit and build code for that Rust edition only. An int
+        mode of "bigint" maps Python int to num_bigint::BigInt so results
+        never overflow. An error mode of "anyhow" makes main return
+        anyhow::Result<()> and propagate errors with `?` and context instead
+        of generated exception structs. A bounds mode of "checked" raises
+        IndexError on out-of-range indices where the default mode falls back
+        to the element type's default value.
+        """
+        return [
+            f"{flag}={getattr(self, attr)}"
+            for attr, _, _, flag in CODEGEN_OPTIONS
+            if getattr(self, attr) is not None
+        ]
+
+    def rust_source(self, *extra: str) -> str:
+        """Transpile the source with depyler and return the generated Rust.
+
+        extra flags are passed after the codegen flags, for modes that only
+        affect the generated text such as `--emit-comments`.
+        """
+        if not self.has_rust:
+            raise RuntimeError(self.skip_reason)
+        result = subprocess.run(
+            [self.depyler, "transpile", str(self.source), *self.codegen_flags(), *extra],
+            capture_output=True,
+            text=True,
+            timeout=COMPILE_TIMEOUT,
+        )
+        if result.returncode != 0:
+            raise GoldenMismatch(
+                f"depyler transpile failed for {self.source.name}:\n{result.stderr}"
+            )
+        return result.stdout
+
+    def rust_source_or_skip(self, *extra: str) -> str:
+        """rust_source(), skipping the calling test if depyler or a flag is missing."""
+        import pytest
+
+        if not self.has_rust:
+            pytest.skip(self.skip_reason)
+        for flag in extra:
+            if not self.supports(flag):
+                pytest.skip(f"depyler does not support {flag}")
+        return self.rust_source(*extra)
+
+    def binary(self) -> Path:
+        """Compile the source with depyler once and return the binary path."""
+        if self._binary is not None:
+            return self._binary
+        if not self.has_rust:
+            raise RuntimeError(self.skip_reason)
+        self._build_dir = tempfile.TemporaryDirectory(prefix="golden_")
+        out = Path(self._build_dir.name) / self.source.stem
+        cmd = [self.depyler, "compile", str(self.source), "-o", str(out)]
+        cmd += self.codegen_flags()
+        result = subprocess.run(
+            cmd,
+            capture_output=True,
+            text=True,
+            timeout=COMPILE_TIMEOUT,
+        )
+        if result.returncode != 0 or not out.exists():
+            raise GoldenMismatch(
+                f"depyler compile failed for {self.source.name}:\n{result.stderr}"
+            )
+        self._binary = out
+        return out
+
+    def run_rust(self, argv: list[str], stdin: str | None = None) -> RunResult:
+        """Run the compiled binary with argv."""
+        return _run([str(self.binary()), *argv], stdin)
+
+    def check(
+        self,
+        argv: list[str],
+        stdout: str,
+        returncode: int = 0,
+        stdin: str | None = None,
+    ) -> list[str]:
+        """Assert every available backend prints stdout and exits with returncode.
+
+        Returns the names of the backends that were checked.
+        """
+        backends = [("python", self.run_python)]
+        if self.has_rust:
+            backends.append(("rust", self.run_rust))
+
+        checked = []
+        for name, runner in backends:
+            result = runner(argv, stdin)
+            if result.returncode != returncode:
+                raise GoldenMismatch(
+                    f"{name} {argv}: exit code {result.returncode}, expected {returncode}\n"
+                    f"stderr: {result.stderr}"
+                )
+            if result.stdout != stdout:
+                raise GoldenMismatch(
+                    f"{name} {argv}: stdout {result.stdout!r}, expected {stdout!r}"
+                )
+            checked.append(name)
+        return checked
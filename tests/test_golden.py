@@ -0,0 +1,391 @@
+"""Tests for the golden end-to-end harness.
+
+A fake depyler stands in for the real transpiler: its "compile" step writes
+a shell wrapper that runs the Python source, so the Rust code path of the
+harness is exercised without a Rust toolchain.
+"""
+
+import stat
+from pathlib import Path
+
+import pytest
+
+from reprorusted_python_cli.golden import (
+    BOUNDS_ENV,
+    DEPYLER_ENV,
+    EDITION_ENV,
+    ERROR_MODE_ENV,
+    INT_MODE_ENV,
+    GoldenCli,
+    GoldenMismatch,
+    find_depyler,
+)
+
+GREETER = """\
+import sys
+
+name = sys.argv[1] if len(sys.argv) > 1 else ""
+if not name:
+    print("missing name", file=sys.stderr)
+    sys.exit(2)
+print(f"Hello, {name}!")
+"""
+
+FAKE_DEPYLER = """\
+#!/bin/sh
+# Usage: depyler compile SRC -o OUT
+printf '#!/bin/sh\\nexec python3 %s "$@"\\n' "$2" > "$4"
+chmod +x "$4"
+"""
+
+WRONG_DEPYLER = """\
+#!/bin/sh
+printf '#!/bin/sh\\necho "Goodbye"\\n' > "$4"
+chmod +x "$4"
+"""
+
+# `compile --help` answer listing every codegen flag the harness can pass
+HELP_ALL = """\
+if [ "$2" = "--help" ]; then
+    echo "Options:"
+    echo "  --target-edition <EDITION>"
+    echo "  --int <MODE>"
+    echo "  --errors <MODE>"
+    echo "  --bounds <MODE>"
+    exit 0
+fi
+"""
+
+# The built "binary" reports the edition it was compiled for
+EDITION_DEPYLER = (
+    """\
+#!/bin/sh
+# Usage: depyler compile SRC -o OUT [--target-edition=EDITION]
+"""
+    + HELP_ALL
+    + """\
+edition="${5:-default}"
+printf '#!/bin/sh\\necho "edition %s"\\n' "${edition#--target-edition=}" > "$4"
+chmod +x "$4"
+"""
+)
+
+# The built "binary" reports every flag after `-o OUT`
+FLAGS_DEPYLER = (
+    "#!/bin/sh\n"
+    + HELP_ALL
+    + """\
+out="$4"
+shift 4
+printf '#!/bin/sh\\necho "flags:%s"\\n' "$*" > "$out"
+chmod +x "$out"
+"""
+)
+
+# An older depyler whose `compile --help` knows only --target-edition
+OLD_DEPYLER = """\
+#!/bin/sh
+if [ "$2" = "--help" ]; then
+    echo "  --target-edition <EDITION>"
+    echo "  --interactive"
+    exit 0
+fi
+printf '#!/bin/sh\\necho "built"\\n' > "$4"
+chmod +x "$4"
+"""
+
+# `transpile` prints a main whose signature depends on --errors
+TRANSPILE_DEPYLER = (
+    """\
+#!/bin/sh
+# Usage: depyler transpile SRC [FLAGS...]
+"""
+    + HELP_ALL
+    + """\
+shift 2
+case " $* " in
+*" --errors=anyhow "*)
+    echo 'fn main() -> anyhow::Result<()> {'
+    echo '    run().context("running cli")?;'
+    echo '    Ok(())'
+    ;;
+*)
+    echo 'fn main() {'
+    ;;
+esac
+echo '}'
+"""
+)
+
+BROKEN_DEPYLER = """\
+#!/bin/sh
+echo "error[E0308]: mismatched types" >&2
+exit 1
+"""
+
+
+def write_executable(path: Path, body: str) -> Path:
+    path.write_text(body)
+    path.chmod(path.stat().st_mode | stat.S_IXUSR)
+    return path
+
+
+@pytest.fixture(autouse=True)
+def clean_env(monkeypatch):
+    """Ignore any depyler settings exported by the surrounding shell."""
+    for env in (DEPYLER_ENV, EDITION_ENV, INT_MODE_ENV, ERROR_MODE_ENV, BOUNDS_ENV):
+        monkeypatch.delenv(env, raising=False)
+
+
+@pytest.fixture
+def greeter(tmp_path):
+    source = tmp_path / "greeter.py"
+    source.write_text(GREETER)
+    return source
+
+
+class TestFindDepyler:
+    def test_env_override(self, tmp_path, monkeypatch):
+        fake = write_executable(tmp_path / "depyler", FAKE_DEPYLER)
+        monkeypatch.setenv(DEPYLER_ENV, str(fake))
+        assert find_depyler() == str(fake)
+
+    def test_env_override_missing(self, tmp_path, monkeypatch):
+        monkeypatch.setenv(DEPYLER_ENV, str(tmp_path / "nope"))
+        assert find_depyler() is None
+
+
+class TestPythonOnly:
+    def test_check_passes(self, greeter, tmp_path, monkeypatch):
+        monkeypatch.setenv(DEPYLER_ENV, str(tmp_path / "nope"))
+        cli = GoldenCli(greeter)
+        assert not cli.has_rust
+        assert cli.check(["Ada"], stdout="Hello, Ada!\n") == ["python"]
+
+    def test_exit_code(self, greeter, tmp_path, monkeypatch):
+        monkeypatch.setenv(DEPYLER_ENV, str(tmp_path / "nope"))
+        cli = GoldenCli(greeter)
+        assert cli.check([], stdout="", returncode=2) == ["python"]
+
+    def test_stdout_mismatch(self, greeter, tmp_path, monkeypatch):
+        monkeypatch.setenv(DEPYLER_ENV, str(tmp_path / "nope"))
+        cli = GoldenCli(greeter)
+        with pytest.raises(GoldenMismatch, match="python"):
+            cli.check(["Ada"], stdout="Hi, Ada!\n")
+
+    def test_exit_code_mismatch(self, greeter, tmp_path, monkeypatch):
+        monkeypatch.setenv(DEPYLER_ENV, str(tmp_path / "nope"))
+        cli = GoldenCli(greeter)
+        with pytest.raises(GoldenMismatch, match="exit code 2"):
+            cli.check([], stdout="")
+
+
+class TestRun:
+    def test_returns_tuple(self, greeter):
+        cli = GoldenCli(greeter)
+        assert cli.run(["Ada"]) == ("Hello, Ada!\n", "", 0)
+
+    def test_failure(self, greeter):
+        cli = GoldenCli(greeter)
+        assert cli.run([]) == ("", "missing name\n", 2)
+
+    def test_stdin_defaults_to_empty(self, tmp_path):
+        source = tmp_path / "cat.py"
+        source.write_text("import sys\nprint(repr(sys.stdin.read()))\n")
+        cli = GoldenCli(source)
+        assert cli.run([])[0] == "''\n"
+        assert cli.run([], stdin="x")[0] == "'x'\n"
+
+
+class TestWithRust:
+    def test_both_backends_checked(self, greeter, tmp_path):
+        fake = write_executable(tmp_path / "depyler", FAKE_DEPYLER)
+        cli = GoldenCli(greeter, depyler=str(fake))
+        assert cli.check(["Ada"], stdout="Hello, Ada!\n") == ["python", "rust"]
+
+    def test_binary_compiled_once(self, greeter, tmp_path):
+        fake = write_executable(tmp_path / "depyler", FAKE_DEPYLER)
+        cli = GoldenCli(greeter, depyler=str(fake))
+        assert cli.binary() == cli.binary()
+
+    def test_rust_mismatch_reported(self, greeter, tmp_path):
+        fake = write_executable(tmp_path / "depyler", WRONG_DEPYLER)
+        cli = GoldenCli(greeter, depyler=str(fake))
+        with pytest.raises(GoldenMismatch, match="rust"):
+            cli.check(["Ada"], stdout="Hello, Ada!\n")
+
+    def test_compile_failure_reported(self, greeter, tmp_path):
+        fake = write_executable(tmp_path / "depyler", BROKEN_DEPYLER)
+        cli = GoldenCli(greeter, depyler=str(fake))
+        with pytest.raises(GoldenMismatch, match="E0308"):
+            cli.check(["Ada"], stdout="Hello, Ada!\n")
+
+
+class TestEdition:
+    def test_default_passes_no_edition(self, greeter, tmp_path):
+        fake = write_executable(tmp_path / "depyler", EDITION_DEPYLER)
+        cli = GoldenCli(greeter, depyler=str(fake))
+        assert cli.run_rust([]).stdout == "edition default\n"
+
+    def test_selected_edition(self, greeter, tmp_path):
+        fake = write_executable(tmp_path / "depyler", EDITION_DEPYLER)
+        cli = GoldenCli(greeter, depyler=str(fake), edition="2018")
+        assert cli.run_rust([]).stdout == "edition 2018\n"
+
+    def test_env_edition(self, greeter, tmp_path, monkeypatch):
+        monkeypatch.setenv(EDITION_ENV, "2024")
+        fake = write_executable(tmp_path / "depyler", EDITION_DEPYLER)
+        cli = GoldenCli(greeter, depyler=str(fake))
+        assert cli.run_rust([]).stdout == "edition 2024\n"
+
+    def test_unknown_edition(self, greeter):
+        with pytest.raises(ValueError, match="2020"):
+            GoldenCli(greeter, edition="2020")
+
+
+class TestIntMode:
+    def test_default_passes_no_flag(self, greeter, tmp_path):
+        fake = write_executable(tmp_path / "depyler", FLAGS_DEPYLER)
+        cli = GoldenCli(greeter, depyler=str(fake))
+        assert cli.run_rust([]).stdout == "flags:\n"
+
+    def test_bigint(self, greeter, tmp_path):
+        fake = write_executable(tmp_path / "depyler", FLAGS_DEPYLER)
+        cli = GoldenCli(greeter, depyler=str(fake), int_mode="bigint")
+        assert cli.run_rust([]).stdout == "flags:--int=bigint\n"
+
+    def test_env_int_mode(self, greeter, tmp_path, monkeypatch):
+        monkeypatch.setenv(INT_MODE_ENV, "bigint")
+        fake = write_executable(tmp_path / "depyler", FLAGS_DEPYLER)
+        cli = GoldenCli(greeter, depyler=str(fake))
+        assert cli.run_rust([]).stdout == "flags:--int=bigint\n"
+
+    def test_with_edition(self, greeter, tmp_path):
+        fake = write_executable(tmp_path / "depyler", FLAGS_DEPYLER)
+        cli = GoldenCli(greeter, depyler=str(fake), edition="2021", int_mode="bigint")
+        assert cli.run_rust([]).stdout == "flags:--target-edition=2021 --int=bigint\n"
+
+    def test_unknown_int_mode(self, greeter):
+        with pytest.raises(ValueError, match="i128"):
+            GoldenCli(greeter, int_mode="i128")
+
+
+class TestErrorMode:
+    def test_anyhow_flag(self, greeter, tmp_path):
+        fake = write_executable(tmp_path / "depyler", FLAGS_DEPYLER)
+        cli = GoldenCli(greeter, depyler=str(fake), errors="anyhow")
+        assert cli.run_rust([]).stdout == "flags:--errors=anyhow\n"
+
+    def test_env_error_mode(self, greeter, tmp_path, monkeypatch):
+        monkeypatch.setenv(ERROR_MODE_ENV, "anyhow")
+        cli = GoldenCli(greeter, depyler=str(tmp_path / "depyler"))
+        assert cli.errors == "anyhow"
+
+    def test_rust_source_anyhow(self, greeter, tmp_path):
+        fake = write_executable(tmp_path / "depyler", TRANSPILE_DEPYLER)
+        cli = GoldenCli(greeter, depyler=str(fake), errors="anyhow")
+        source = cli.rust_source()
+        assert "fn main() -> anyhow::Result<()>" in source
+        assert ".context(" in source
+
+    def test_rust_source_extra_flags(self, greeter, tmp_path):
+        fake = write_executable(tmp_path / "depyler", TRANSPILE_DEPYLER)
+        cli = GoldenCli(greeter, depyler=str(fake))
+        assert "anyhow" in cli.rust_source("--errors=anyhow")
+
+    def test_rust_source_default(self, greeter, tmp_path):
+        fake = write_executable(tmp_path / "depyler", TRANSPILE_DEPYLER)
+        cli = GoldenCli(greeter, depyler=str(fake))
+        assert "anyhow" not in cli.rust_source()
+
+    def test_transpile_failure_reported(self, greeter, tmp_path):
+        fake = write_executable(tmp_path / "depyler", BROKEN_DEPYLER)
+        cli = GoldenCli(greeter, depyler=str(fake))
+        with pytest.raises(GoldenMismatch, match="transpile failed"):
+            cli.rust_source()
+
+    def test_unknown_error_mode(self, greeter):
+        with pytest.raises(ValueError, match="eyre"):
+            GoldenCli(greeter, errors="eyre")
+
+
+class TestBounds:
+    def test_checked_flag(self, greeter, tmp_path):
+        fake = write_executable(tmp_path / "depyler", FLAGS_DEPYLER)
+        cli = GoldenCli(greeter, depyler=str(fake), bounds="checked")
+        assert cli.run_rust([]).stdout == "flags:--bounds=checked\n"
+
+    def test_env_bounds(self, greeter, tmp_path, monkeypatch):
+        monkeypatch.setenv(BOUNDS_ENV, "checked")
+        cli = GoldenCli(greeter, depyler=str(tmp_path / "depyler"))
+        assert cli.codegen_flags()[-1] == "--bounds=checked"
+
+    def test_all_modes_in_order(self, greeter, tmp_path):
+        cli = GoldenCli(
+            greeter,
+            depyler=str(tmp_path / "depyler"),
+            edition="2021",
+            int_mode="bigint",
+            errors="anyhow",
+            bounds="checked",
+        )
+        assert cli.codegen_flags() == [
+            "--target-edition=2021",
+            "--int=bigint",
+            "--errors=anyhow",
+            "--bounds=checked",
+        ]
+
+    def test_unknown_bounds(self, greeter):
+        with pytest.raises(ValueError, match="wrapping"):
+            GoldenCli(greeter, bounds="wrapping")
+
+
+class TestFlagProbe:
+    def test_supported_flags(self, greeter, tmp_path):
+        fake = write_executable(tmp_path / "depyler", FLAGS_DEPYLER)
+        cli = GoldenCli(greeter, depyler=str(fake), int_mode="bigint", bounds="checked")
+        assert cli.unsupported_flags() == []
+        assert cli.has_rust
+
+    def test_unsupported_flag_skips_rust(self, greeter, tmp_path):
+        fake = write_executable(tmp_path / "depyler", OLD_DEPYLER)
+        cli = GoldenCli(greeter, depyler=str(fake), edition="2021", int_mode="bigint")
+        assert cli.unsupported_flags() == ["--int=bigint"]
+        assert not cli.has_rust
+        assert cli.skip_reason == "depyler does not support --int=bigint"
+        assert cli.check(["Ada"], stdout="Hello, Ada!\n") == ["python"]
+
+    def test_unsupported_flag_not_compiled(self, greeter, tmp_path):
+        fake = write_executable(tmp_path / "depyler", OLD_DEPYLER)
+        cli = GoldenCli(greeter, depyler=str(fake), errors="anyhow")
+        with pytest.raises(RuntimeError, match="--errors=anyhow"):
+            cli.rust_source()
+
+    def test_supported_edition(self, greeter, tmp_path):
+        fake = write_executable(tmp_path / "depyler", OLD_DEPYLER)
+        cli = GoldenCli(greeter, depyler=str(fake), edition="2018")
+        assert cli.has_rust
+        assert cli.run_rust([]).stdout == "built\n"
+
+    def test_supports(self, greeter, tmp_path):
+        fake = write_executable(tmp_path / "depyler", OLD_DEPYLER)
+        cli = GoldenCli(greeter, depyler=str(fake))
+        assert cli.supports("--target-edition")
+        assert not cli.supports("--int=bigint")
+
+    def test_supports_without_depyler(self, greeter, tmp_path, monkeypatch):
+        monkeypatch.setenv(DEPYLER_ENV, str(tmp_path / "nope"))
+        cli = GoldenCli(greeter)
+        assert not cli.supports("--int")
+
+    def test_rust_source_or_skip(self, greeter, tmp_path):
+        fake = write_executable(tmp_path / "depyler", OLD_DEPYLER)
+        cli = GoldenCli(greeter, depyler=str(fake))
+        with pytest.raises(pytest.skip.Exception, match="--strip-asserts"):
+            cli.rust_source_or_skip("--strip-asserts")
+
+    def test_no_flags_skips_probe(self, greeter, tmp_path):
+        fake = write_executable(tmp_path / "depyler", BROKEN_DEPYLER)
+        cli = GoldenCli(greeter, depyler=str(fake))
+        assert cli.has_rust
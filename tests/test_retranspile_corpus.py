@@ -0,0 +1,117 @@
+"""Tests for parallel corpus re-transpilation.
+
+A fake depyler script stands in for the real transpiler so the parallel
+path can be compared with a serial run.
+"""
+
+import random
+import stat
+import sys
+import time
+
+import pytest
+
+try:
+    import scripts.retranspile_corpus as retranspile_corpus
+    import scripts.transpile_cache as transpile_cache
+    from scripts.retranspile_corpus import collect_python_files, transpile_all
+    from scripts.transpile_cache import RUN_FAILED, TranspileCache
+except ImportError:
+    transpile_all = None
+
+# Emits a Rust comment naming the file, fails on files containing "FAIL"
+FAKE_DEPYLER = """\
+#!/bin/sh
+if grep -q FAIL "$2"; then
+    echo "error: unsupported construct in $2" >&2
+    exit 1
+fi
+echo "// transpiled from $(basename "$2")"
+echo "fn main() {}"
+"""
+
+
+@pytest.fixture
+def depyler_bin(tmp_path):
+    path = tmp_path / "depyler"
+    path.write_text(FAKE_DEPYLER)
+    path.chmod(path.stat().st_mode | stat.S_IXUSR)
+    return path
+
+
+@pytest.fixture
+def examples_dir(tmp_path):
+    root = tmp_path / "examples"
+    for i in range(12):
+        example = root / f"example_{i:02d}"
+        example.mkdir(parents=True)
+        body = f"FAIL {i}\n" if i % 5 == 0 else f"print({i})\n"
+        (example / f"tool_{i:02d}.py").write_text(body)
+        (example / f"helper_{i:02d}.py").write_text(f"x = {i}\n")
+    (root / "not_an_example").mkdir()
+    (root / "not_an_example" / "skip.py").write_text("print('skip')\n")
+    return root
+
+
+def jittery(python_path, depyler_bin):
+    """Fake transpile that finishes out of order."""
+    time.sleep(random.uniform(0, 0.02))
+    return f"// {python_path.name}", None
+
+
+class TestCollect:
+    def test_sorted_and_filtered(self, examples_dir):
+        if transpile_all is None:
+            pytest.skip("retranspile_corpus dependencies not installed")
+        files = collect_python_files(examples_dir)
+        assert len(files) == 24
+        assert files == sorted(files)
+        assert all(f.parent.name.startswith("example_") for f in files)
+
+
+class TestParallel:
+    def test_matches_serial_run(self, examples_dir, depyler_bin):
+        if transpile_all is None:
+            pytest.skip("retranspile_corpus dependencies not installed")
+        files = collect_python_files(examples_dir)
+        serial = transpile_all(files, depyler_bin, jobs=1)
+        parallel = transpile_all(files, depyler_bin, jobs=8)
+        assert parallel == serial
+        assert serial[0] == ("// transpiled from helper_00.py\nfn main() {}\n", None)
+        assert serial[1][0] is None
+        assert "unsupported construct" in serial[1][1]
+
+    def test_order_independent_of_completion(self, examples_dir, depyler_bin):
+        if transpile_all is None:
+            pytest.skip("retranspile_corpus dependencies not installed")
+        files = collect_python_files(examples_dir)
+        results = transpile_all(files, depyler_bin, jobs=8, transpile=jittery)
+        assert [rust for rust, _ in results] == [f"// {f.name}" for f in files]
+
+    def test_shared_cache(self, examples_dir, depyler_bin, tmp_path):
+        if transpile_all is None:
+            pytest.skip("retranspile_corpus dependencies not installed")
+        files = collect_python_files(examples_dir)
+        cache = TranspileCache(tmp_path / "cache.json", "build")
+        first = transpile_all(files, depyler_bin, cache, jobs=8)
+        second = transpile_all(files, depyler_bin, cache, jobs=8)
+        assert first == second
+        assert cache.misses == len(files)
+        assert cache.hits == len(files)
+
+    def test_missing_depyler_not_cached(self, examples_dir, tmp_path):
+        if transpile_all is None:
+            pytest.skip("retranspile_corpus dependencies not installed")
+        files = collect_python_files(examples_dir)[:2]
+        cache = TranspileCache(tmp_path / "cache.json", "build")
+        results = transpile_all(files, tmp_path / "missing-depyler", cache, jobs=2)
+        assert all(error.startswith(RUN_FAILED) for _, error in results)
+        assert cache.entries == {}
+
+
+class TestImports:
+    def test_single_cache_module(self):
+        if transpile_all is None:
+            pytest.skip("retranspile_corpus dependencies not installed")
+        assert retranspile_corpus.TranspileCache is transpile_cache.TranspileCache
+        assert "transpile_cache" not in sys.modules
@@ -0,0 +1,161 @@
+"""Tests for the content-hash transpilation cache.
+
+Unchanged Python sources must be served from the cache instead of
+re-running depyler.
+"""
+
+import json
+
+import pytest
+
+try:
+    from scripts.transpile_cache import (
+        RUN_FAILED,
+        TIMEOUT,
+        TranspileCache,
+        depyler_fingerprint,
+        source_hash,
+        transpile_cached,
+    )
+except ImportError:
+    TranspileCache = None
+
+
+class CountingTranspiler:
+    """Fake transpile_python that records how often it is called."""
+
+    def __init__(self, result=("fn main() {}", None)):
+        self.calls = 0
+        self.result = result
+
+    def __call__(self, python_path, depyler_bin):
+        self.calls += 1
+        return self.result
+
+
+@pytest.fixture
+def depyler_bin(tmp_path):
+    path = tmp_path / "depyler"
+    path.write_text("#!/bin/sh\n")
+    return path
+
+
+@pytest.fixture
+def source(tmp_path):
+    path = tmp_path / "tool.py"
+    path.write_text("print('hi')\n")
+    return path
+
+
+class TestSourceHash:
+    def test_stable(self):
+        if TranspileCache is None:
+            pytest.skip("transpile_cache not implemented")
+        assert source_hash("x = 1\n") == source_hash("x = 1\n")
+
+    def test_content_sensitive(self):
+        if TranspileCache is None:
+            pytest.skip("transpile_cache not implemented")
+        assert source_hash("x = 1\n") != source_hash("x = 2\n")
+
+
+class TestTranspileCached:
+    def test_second_run_is_cache_hit(self, tmp_path, depyler_bin, source):
+        if TranspileCache is None:
+            pytest.skip("transpile_cache not implemented")
+        cache_path = tmp_path / "cache.json"
+        fake = CountingTranspiler()
+        code = source.read_text()
+
+        cache = TranspileCache.load(cache_path, depyler_fingerprint(depyler_bin))
+        first = transpile_cached(source, code, depyler_bin, fake, cache)
+        cache.save()
+
+        cache = TranspileCache.load(cache_path, depyler_fingerprint(depyler_bin))
+        second = transpile_cached(source, code, depyler_bin, fake, cache)
+
+        assert fake.calls == 1
+        assert cache.hits == 1
+        assert cache.misses == 0
+        assert first == second == ("fn main() {}", None)
+
+    def test_changed_source_is_miss(self, tmp_path, depyler_bin, source):
+        if TranspileCache is None:
+            pytest.skip("transpile_cache not implemented")
+        fake = CountingTranspiler()
+        cache = TranspileCache.load(tmp_path / "cache.json", depyler_fingerprint(depyler_bin))
+        transpile_cached(source, "print(1)\n", depyler_bin, fake, cache)
+        transpile_cached(source, "print(2)\n", depyler_bin, fake, cache)
+        assert fake.calls == 2
+        assert cache.misses == 2
+
+    def test_errors_are_cached(self, tmp_path, depyler_bin, source):
+        if TranspileCache is None:
+            pytest.skip("transpile_cache not implemented")
+        fake = CountingTranspiler(result=(None, "unsupported: async"))
+        cache = TranspileCache.load(tmp_path / "cache.json", depyler_fingerprint(depyler_bin))
+        transpile_cached(source, "x\n", depyler_bin, fake, cache)
+        assert transpile_cached(source, "x\n", depyler_bin, fake, cache) == (
+            None,
+            "unsupported: async",
+        )
+        assert fake.calls == 1
+
+    def test_timeouts_not_cached(self, tmp_path, depyler_bin, source):
+        if TranspileCache is None:
+            pytest.skip("transpile_cache not implemented")
+        fake = CountingTranspiler(result=(None, TIMEOUT))
+        cache = TranspileCache.load(tmp_path / "cache.json", depyler_fingerprint(depyler_bin))
+        transpile_cached(source, "x\n", depyler_bin, fake, cache)
+        transpile_cached(source, "x\n", depyler_bin, fake, cache)
+        assert fake.calls == 2
+
+    def test_run_failures_not_cached(self, tmp_path, depyler_bin, source):
+        if TranspileCache is None:
+            pytest.skip("transpile_cache not implemented")
+        fake = CountingTranspiler(result=(None, f"{RUN_FAILED}[Errno 26] Text file busy"))
+        cache = TranspileCache.load(tmp_path / "cache.json", depyler_fingerprint(depyler_bin))
+        transpile_cached(source, "x\n", depyler_bin, fake, cache)
+        transpile_cached(source, "x\n", depyler_bin, fake, cache)
+        assert fake.calls == 2
+        assert cache.entries == {}
+
+    def test_no_cache_always_transpiles(self, depyler_bin, source):
+        if TranspileCache is None:
+            pytest.skip("transpile_cache not implemented")
+        fake = CountingTranspiler()
+        transpile_cached(source, "x\n", depyler_bin, fake, None)
+        transpile_cached(source, "x\n", depyler_bin, fake, None)
+        assert fake.calls == 2
+
+
+class TestInvalidation:
+    def test_new_depyler_discards_cache(self, tmp_path, depyler_bin):
+        if TranspileCache is None:
+            pytest.skip("transpile_cache not implemented")
+        cache_path = tmp_path / "cache.json"
+        cache = TranspileCache.load(cache_path, "old-build")
+        cache.put("x\n", "fn main() {}", None)
+        cache.save()
+
+        cache = TranspileCache.load(cache_path, "new-build")
+        assert cache.get("x\n") is None
+
+    def test_corrupt_file_starts_empty(self, tmp_path):
+        if TranspileCache is None:
+            pytest.skip("transpile_cache not implemented")
+        cache_path = tmp_path / "cache.json"
+        cache_path.write_text("{not json")
+        cache = TranspileCache.load(cache_path, "build")
+        assert cache.entries == {}
+
+    def test_saved_format(self, tmp_path):
+        if TranspileCache is None:
+            pytest.skip("transpile_cache not implemented")
+        cache_path = tmp_path / "cache.json"
+        cache = TranspileCache.load(cache_path, "build")
+        cache.put("x\n", "fn main() {}", None)
+        cache.save()
+        data = json.loads(cache_path.read_text())
+        assert data["fingerprint"] == "build"
+        assert data["entries"][source_hash("x\n")]["rust_code"] == "fn main() {}"